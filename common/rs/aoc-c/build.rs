@@ -0,0 +1,12 @@
+fn main() {
+    println!("cargo::rerun-if-changed=src/lib.rs");
+    println!("cargo::rerun-if-changed=cbindgen.toml");
+
+    // the committed include/aoc.h is checked against this one by the tests
+    let dir = std::env::var("CARGO_MANIFEST_DIR").expect("cannot find CARGO_MANIFEST_DIR");
+    let out_dir = std::env::var("OUT_DIR").expect("cannot find OUT_DIR");
+    let config = cbindgen::Config::from_root_or_default(&dir);
+    cbindgen::generate_with_config(&dir, config)
+        .expect("cannot generate the C header")
+        .write_to_file(format!("{out_dir}/aoc.h"));
+}
@@ -0,0 +1,86 @@
+/* Generated by cbindgen from src/lib.rs, do not edit. */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Size of an output buffer fitting every answer with its NUL.
+ */
+#define AOC_RESULT_SIZE 65
+
+/**
+ * Outcome of [`aoc_solve`].
+ */
+typedef enum AocStatus {
+  /**
+   * The answer is in the output buffer.
+   */
+  AOC_STATUS_OK = 0,
+  /**
+   * The day is not in the library.
+   */
+  AOC_STATUS_UNSUPPORTED_DAY,
+  /**
+   * The part is neither 1 nor 2.
+   */
+  AOC_STATUS_INVALID_PART,
+  /**
+   * The input is not UTF-8.
+   */
+  AOC_STATUS_INVALID_INPUT,
+  /**
+   * A pointer is NULL.
+   */
+  AOC_STATUS_NULL_POINTER,
+  /**
+   * The output buffer is smaller than the answer with its NUL, whose length is in
+   * `out_len`.
+   */
+  AOC_STATUS_BUFFER_TOO_SMALL,
+  /**
+   * The answer is longer than the result strings.
+   */
+  AOC_STATUS_ANSWER_TOO_LONG,
+  /**
+   * The solver panicked, like on an unexpected input; with the `std` feature only.
+   */
+  AOC_STATUS_PANICKED,
+} AocStatus;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Whether `day` of the workspace event is in the library.
+ */
+bool aoc_has_day(uint8_t day);
+
+/**
+ * Solve `part` of `day` of the workspace event on the `input_len` bytes of `input`,
+ * writing the answer with a NUL to `out` and its length without the NUL to `out_len`.
+ *
+ * `out_len` holds the size of `out` on the call, [`AOC_RESULT_SIZE`] fits every answer.
+ *
+ * # Safety
+ * `input` points to `input_len` readable bytes, or is NULL with `input_len` 0, `out_len`
+ * points to a writable `size_t` and `out` to as many writable bytes as it holds.
+ */
+enum AocStatus aoc_solve(uint8_t day,
+                         uint8_t part,
+                         const uint8_t *input,
+                         size_t input_len,
+                         char *out,
+                         size_t *out_len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* AOC_H */
@@ -0,0 +1,222 @@
+//! C ABI of the solvers of the days, as a shared and a static library with the header
+//! `include/aoc.h`, checked by the tests against the one generated by the build.
+//!
+//! [`aoc_solve`] writes the answer of a part as the NUL-terminated result string of
+//! `embedded-aoc`, `NO PART 2` for a day without part 2. Without the `std` feature it is
+//! a `no_std` static library for firmware, with the days selected by the features of
+//! `embedded-aoc` and a panic halting in a loop.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::ffi::c_char;
+use core::{ptr, slice, str};
+
+use embedded_aoc::{DEFAULT_YEAR, Day, PartResult};
+
+/// Size of an output buffer fitting every answer with its NUL.
+pub const AOC_RESULT_SIZE: usize = 65;
+
+const _: () = assert!(AOC_RESULT_SIZE == embedded_aoc::RESULT_LEN + 1);
+
+/// Outcome of [`aoc_solve`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AocStatus {
+    /// The answer is in the output buffer.
+    Ok = 0,
+    /// The day is not in the library.
+    UnsupportedDay,
+    /// The part is neither 1 nor 2.
+    InvalidPart,
+    /// The input is not UTF-8.
+    InvalidInput,
+    /// A pointer is NULL.
+    NullPointer,
+    /// The output buffer is smaller than the answer with its NUL, whose length is in
+    /// `out_len`.
+    BufferTooSmall,
+    /// The answer is longer than the result strings.
+    AnswerTooLong,
+    /// The solver panicked, like on an unexpected input; with the `std` feature only.
+    Panicked,
+}
+
+fn day(day: u8) -> Option<Day> {
+    Day::ALL
+        .iter()
+        .copied()
+        .find(|d| d.id() == (DEFAULT_YEAR, day))
+}
+
+fn solve(day: Day, part: u8, input: &str) -> Result<PartResult, AocStatus> {
+    let mut result = PartResult::new();
+    match part {
+        1 => day.part_1(&mut result, input),
+        2 => day.part_2(&mut result, input),
+        _ => return Err(AocStatus::InvalidPart),
+    }
+    .map_err(|_| AocStatus::AnswerTooLong)?;
+    Ok(result)
+}
+
+#[cfg(feature = "std")]
+fn guarded(day: Day, part: u8, input: &str) -> Result<PartResult, AocStatus> {
+    std::panic::catch_unwind(|| solve(day, part, input)).unwrap_or(Err(AocStatus::Panicked))
+}
+
+#[cfg(not(feature = "std"))]
+fn guarded(day: Day, part: u8, input: &str) -> Result<PartResult, AocStatus> {
+    solve(day, part, input)
+}
+
+/// Whether `day` of the workspace event is in the library.
+#[unsafe(no_mangle)]
+pub extern "C" fn aoc_has_day(day: u8) -> bool {
+    self::day(day).is_some()
+}
+
+/// Solve `part` of `day` of the workspace event on the `input_len` bytes of `input`,
+/// writing the answer with a NUL to `out` and its length without the NUL to `out_len`.
+///
+/// `out_len` holds the size of `out` on the call, [`AOC_RESULT_SIZE`] fits every answer.
+///
+/// # Safety
+/// `input` points to `input_len` readable bytes, or is NULL with `input_len` 0, `out_len`
+/// points to a writable `size_t` and `out` to as many writable bytes as it holds.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    input_len: usize,
+    out: *mut c_char,
+    out_len: *mut usize,
+) -> AocStatus {
+    if (input.is_null() && input_len > 0) || out.is_null() || out_len.is_null() {
+        return AocStatus::NullPointer;
+    }
+
+    let Some(day) = self::day(day) else {
+        return AocStatus::UnsupportedDay;
+    };
+
+    let input = if input_len == 0 {
+        &[]
+    } else {
+        // SAFETY: `input` points to `input_len` readable bytes.
+        unsafe { slice::from_raw_parts(input, input_len) }
+    };
+    let Ok(input) = str::from_utf8(input) else {
+        return AocStatus::InvalidInput;
+    };
+
+    let result = match guarded(day, part, input) {
+        Ok(result) => result,
+        Err(status) => return status,
+    };
+
+    // SAFETY: `out_len` points to a writable `size_t` holding the size of `out`.
+    let size = unsafe { out_len.replace(result.len()) };
+    if result.len() >= size {
+        return AocStatus::BufferTooSmall;
+    }
+
+    // SAFETY: `out` points to `size` writable bytes, more than the answer.
+    unsafe {
+        ptr::copy_nonoverlapping(result.as_ptr().cast(), out, result.len());
+        out.add(result.len()).write(0);
+    }
+
+    AocStatus::Ok
+}
+
+#[cfg(not(feature = "std"))]
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::ffi::CStr;
+
+    fn call(day: u8, part: u8, input: &[u8], size: usize) -> (AocStatus, usize, String) {
+        let mut out = vec![0x7f; size.max(1)];
+        let mut out_len = size;
+        let status = unsafe {
+            aoc_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                out.as_mut_ptr(),
+                &raw mut out_len,
+            )
+        };
+        let answer = if status == AocStatus::Ok {
+            unsafe { CStr::from_ptr(out.as_ptr()) }
+                .to_string_lossy()
+                .into_owned()
+        } else {
+            String::new()
+        };
+        (status, out_len, answer)
+    }
+
+    const INPUT: &[u8] = b"L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+
+    #[test]
+    fn test_solve() {
+        assert!(aoc_has_day(1));
+        assert_eq!(
+            call(1, 1, INPUT, AOC_RESULT_SIZE),
+            (AocStatus::Ok, 1, "3".to_string())
+        );
+        assert_eq!(
+            call(1, 2, INPUT, AOC_RESULT_SIZE),
+            (AocStatus::Ok, 1, "6".to_string())
+        );
+        assert_eq!(
+            call(12, 2, b"", AOC_RESULT_SIZE),
+            (
+                AocStatus::Ok,
+                embedded_aoc::NO_PART_2.len(),
+                embedded_aoc::NO_PART_2.to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(!aoc_has_day(26));
+        assert_eq!(
+            call(26, 1, INPUT, AOC_RESULT_SIZE).0,
+            AocStatus::UnsupportedDay
+        );
+        assert_eq!(call(1, 3, INPUT, AOC_RESULT_SIZE).0, AocStatus::InvalidPart);
+        assert_eq!(
+            call(1, 1, b"L6\xff", AOC_RESULT_SIZE).0,
+            AocStatus::InvalidInput
+        );
+        assert_eq!(
+            call(1, 1, INPUT, 1),
+            (AocStatus::BufferTooSmall, 1, String::new())
+        );
+        assert_eq!(call(1, 1, b"X1\n", AOC_RESULT_SIZE).0, AocStatus::Panicked);
+
+        let mut out_len = AOC_RESULT_SIZE;
+        let status = unsafe { aoc_solve(1, 1, ptr::null(), 1, ptr::null_mut(), &raw mut out_len) };
+        assert_eq!(status, AocStatus::NullPointer);
+    }
+
+    #[test]
+    fn test_header() {
+        let generated = concat!(env!("OUT_DIR"), "/aoc.h");
+        assert!(
+            include_str!(concat!(env!("OUT_DIR"), "/aoc.h")) == include_str!("../include/aoc.h"),
+            "include/aoc.h is out of date, copy {generated} over it"
+        );
+    }
+}
@@ -0,0 +1,35 @@
+"""Solvers of the Advent of Code days of the workspace."""
+
+class SolverPanic(RuntimeError):
+    """Panic of a solver, like on an unexpected input."""
+
+def solve(day: int, part: int, input: str) -> int | None:
+    """Answer of a part of a day with the default variant, None for a missing part."""
+
+def day01_part_1(input: str) -> int: ...
+def day01_part_2(input: str) -> int: ...
+def day02_part_1(input: str) -> int: ...
+def day02_part_2(input: str) -> int: ...
+def day03_part_1(input: str) -> int: ...
+def day03_part_2(input: str) -> int: ...
+def day04_part_1(input: str) -> int: ...
+def day04_part_2(input: str) -> int: ...
+def day05_part_1(input: str) -> int: ...
+def day05_part_2(input: str) -> int: ...
+def day06_part_1(input: str) -> int: ...
+def day06_part_2(input: str) -> int: ...
+def day07_part_1(input: str) -> int: ...
+def day07_part_2(input: str) -> int: ...
+def day08_part_1(input: str, connections: int = 1000) -> int:
+    """Connecting the `connections` closest pairs, 10 for the example."""
+
+def day08_part_2(input: str) -> int: ...
+def day09_part_1(input: str) -> int: ...
+def day09_part_2(input: str) -> int: ...
+def day10_part_1(input: str) -> int: ...
+def day10_part_2(input: str, float: str = "f32") -> int:
+    """With the simplex in `float`, "f32" or "f64"."""
+
+def day11_part_1(input: str) -> int: ...
+def day11_part_2(input: str) -> int: ...
+def day12_part_1(input: str) -> int: ...
@@ -0,0 +1,220 @@
+//! Python extension module `aoc_py` with the solvers of the days, built with maturin.
+//!
+//! Every part of every day is a function like `day01_part_1(input)` returning a Python
+//! `int`, with the variants of the parameterized days as keyword arguments, and
+//! `solve(day, part, input)` dispatches on the day. A panic of a solver, like on an
+//! unexpected input, raises `SolverPanic` instead of aborting the interpreter.
+
+use std::any::Any;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+
+use pyo3::create_exception;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyInt;
+
+use solution::Solution;
+
+create_exception!(
+    aoc_py,
+    SolverPanic,
+    PyRuntimeError,
+    "Panic of a solver, like on an unexpected input."
+);
+
+/// Run `solver` without the GIL, turning a panic into [`SolverPanic`].
+fn guarded<T: Send>(py: Python<'_>, solver: impl FnOnce() -> T + Send) -> PyResult<T> {
+    py.detach(|| panic::catch_unwind(AssertUnwindSafe(solver)))
+        .map_err(|payload| SolverPanic::new_err(panic_message(&*payload)))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Python function solving a part of a day without parameters.
+macro_rules! part {
+    ($name:ident, $solver:path, $answer:ty) => {
+        #[pyfunction]
+        fn $name(py: Python<'_>, input: &str) -> PyResult<$answer> {
+            guarded(py, || $solver(input))
+        }
+    };
+}
+
+part!(day01_part_1, day01::part_1, usize);
+part!(day01_part_2, day01::part_2, i64);
+part!(day02_part_1, day02::part_1, u64);
+part!(day02_part_2, day02::part_2, u64);
+part!(day03_part_1, day03::part_1, u64);
+part!(day03_part_2, day03::part_2, u64);
+part!(day04_part_1, day04::part_1, usize);
+part!(day04_part_2, day04::part_2, usize);
+part!(day05_part_1, day05::part_1, usize);
+part!(day05_part_2, day05::part_2, u64);
+part!(day06_part_1, day06::part_1, u64);
+part!(day06_part_2, day06::part_2, u64);
+part!(day07_part_1, day07::part_1, usize);
+part!(day07_part_2, day07::part_2, u64);
+part!(day08_part_2, day08::part_2, i64);
+part!(day09_part_1, day09::part_1, u64);
+part!(day09_part_2, day09::part_2, u64);
+part!(day10_part_1, day10::part_1, u64);
+part!(day11_part_1, day11::part_1, u64);
+part!(day11_part_2, day11::part_2, u64);
+part!(day12_part_1, day12::part_1, usize);
+
+/// Part 1 of day 8 connecting the `connections` closest pairs, 10 for the example.
+#[pyfunction]
+#[pyo3(signature = (input, connections = 1000))]
+fn day08_part_1(py: Python<'_>, input: &str, connections: usize) -> PyResult<u32> {
+    guarded(py, || day08::part_1_connecting(input, connections))
+}
+
+/// Part 2 of day 10 with the simplex in `float`, `"f32"` or `"f64"`.
+#[pyfunction]
+#[pyo3(signature = (input, float = "f32"))]
+fn day10_part_2(py: Python<'_>, input: &str, float: &str) -> PyResult<u64> {
+    match float {
+        "f32" => guarded(py, || day10::part_2::<f32>(input)),
+        "f64" => guarded(py, || day10::part_2::<f64>(input)),
+        _ => Err(PyValueError::new_err(format!(
+            "invalid float {float}, expected f32 or f64"
+        ))),
+    }
+}
+
+fn solve_day<S: Solution>(py: Python<'_>, part: u8, input: &str) -> PyResult<Option<Py<PyAny>>> {
+    let answer = match part {
+        1 => guarded(py, || Some(S::part_1(input).to_string()))?,
+        2 => guarded(py, || S::part_2(input).as_ref().map(ToString::to_string))?,
+        _ => return Err(PyValueError::new_err(format!("invalid part {part}"))),
+    };
+    answer.map(|answer| to_int(py, answer)).transpose()
+}
+
+/// Python `int` of a decimal answer, whatever its Rust type.
+fn to_int(py: Python<'_>, answer: impl Display) -> PyResult<Py<PyAny>> {
+    Ok(py
+        .get_type::<PyInt>()
+        .call1((answer.to_string(),))?
+        .unbind())
+}
+
+/// Answer of a part of a day of the workspace event with the default variant, `None`
+/// for a day without that part.
+#[pyfunction]
+fn solve(py: Python<'_>, day: u8, part: u8, input: &str) -> PyResult<Option<Py<PyAny>>> {
+    match day {
+        1 => solve_day::<day01::Day01>(py, part, input),
+        2 => solve_day::<day02::Day02>(py, part, input),
+        3 => solve_day::<day03::Day03>(py, part, input),
+        4 => solve_day::<day04::Day04>(py, part, input),
+        5 => solve_day::<day05::Day05>(py, part, input),
+        6 => solve_day::<day06::Day06>(py, part, input),
+        7 => solve_day::<day07::Day07>(py, part, input),
+        8 => solve_day::<day08::Day08>(py, part, input),
+        9 => solve_day::<day09::Day09>(py, part, input),
+        10 => solve_day::<day10::Day10>(py, part, input),
+        11 => solve_day::<day11::Day11>(py, part, input),
+        12 => solve_day::<day12::Day12>(py, part, input),
+        _ => Err(PyValueError::new_err(format!("invalid day {day}"))),
+    }
+}
+
+#[pymodule]
+fn aoc_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("SolverPanic", m.py().get_type::<SolverPanic>())?;
+
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+
+    m.add_function(wrap_pyfunction!(day01_part_1, m)?)?;
+    m.add_function(wrap_pyfunction!(day01_part_2, m)?)?;
+    m.add_function(wrap_pyfunction!(day02_part_1, m)?)?;
+    m.add_function(wrap_pyfunction!(day02_part_2, m)?)?;
+    m.add_function(wrap_pyfunction!(day03_part_1, m)?)?;
+    m.add_function(wrap_pyfunction!(day03_part_2, m)?)?;
+    m.add_function(wrap_pyfunction!(day04_part_1, m)?)?;
+    m.add_function(wrap_pyfunction!(day04_part_2, m)?)?;
+    m.add_function(wrap_pyfunction!(day05_part_1, m)?)?;
+    m.add_function(wrap_pyfunction!(day05_part_2, m)?)?;
+    m.add_function(wrap_pyfunction!(day06_part_1, m)?)?;
+    m.add_function(wrap_pyfunction!(day06_part_2, m)?)?;
+    m.add_function(wrap_pyfunction!(day07_part_1, m)?)?;
+    m.add_function(wrap_pyfunction!(day07_part_2, m)?)?;
+    m.add_function(wrap_pyfunction!(day08_part_1, m)?)?;
+    m.add_function(wrap_pyfunction!(day08_part_2, m)?)?;
+    m.add_function(wrap_pyfunction!(day09_part_1, m)?)?;
+    m.add_function(wrap_pyfunction!(day09_part_2, m)?)?;
+    m.add_function(wrap_pyfunction!(day10_part_1, m)?)?;
+    m.add_function(wrap_pyfunction!(day10_part_2, m)?)?;
+    m.add_function(wrap_pyfunction!(day11_part_1, m)?)?;
+    m.add_function(wrap_pyfunction!(day11_part_2, m)?)?;
+    m.add_function(wrap_pyfunction!(day12_part_1, m)?)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+
+    fn attach<R>(f: impl FnOnce(Python<'_>) -> R) -> R {
+        Python::initialize();
+        Python::attach(f)
+    }
+
+    fn answer(py: Python<'_>, answer: Option<Py<PyAny>>) -> Option<u64> {
+        answer.map(|answer| answer.extract(py).unwrap())
+    }
+
+    #[test]
+    fn test_solve() {
+        attach(|py| {
+            assert_eq!(answer(py, solve(py, 1, 1, INPUT).unwrap()), Some(3));
+            assert_eq!(answer(py, solve(py, 1, 2, INPUT).unwrap()), Some(6));
+            assert_eq!(answer(py, solve(py, 12, 2, "").unwrap()), None);
+
+            assert_eq!(day01_part_1(py, INPUT).unwrap(), 3);
+            assert_eq!(day01_part_2(py, INPUT).unwrap(), 6);
+        });
+    }
+
+    #[test]
+    fn test_errors() {
+        attach(|py| {
+            let error = solve(py, 26, 1, INPUT).unwrap_err();
+            assert!(error.is_instance_of::<PyValueError>(py));
+            assert_eq!(error.to_string(), "ValueError: invalid day 26");
+
+            let error = solve(py, 1, 3, INPUT).unwrap_err();
+            assert!(error.is_instance_of::<PyValueError>(py));
+
+            let error = day10_part_2(py, "", "f16").unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                "ValueError: invalid float f16, expected f32 or f64"
+            );
+        });
+    }
+
+    #[test]
+    fn test_panic() {
+        attach(|py| {
+            let error = solve(py, 1, 1, "X1\n").unwrap_err();
+            assert!(error.is_instance_of::<SolverPanic>(py));
+            assert!(error.is_instance_of::<PyRuntimeError>(py));
+
+            assert!(day01_part_1(py, "X1\n").is_err());
+        });
+    }
+}
@@ -0,0 +1,342 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
+
+use aoc::AnswerCheck;
+
+use crate::{Day, DayOutcome, Failure};
+
+/// Times the median of its day above which the time of an input is an outlier.
+pub const OUTLIER_FACTOR: u32 = 10;
+
+/// Inputs of a day that ran needed for a median worth comparing against.
+pub const OUTLIER_MIN_INPUTS: usize = 3;
+
+/// Extensions of the files next to the inputs that are not inputs: encrypted and locked
+/// inputs, the marker of the fetched examples, manifests and sources.
+const NOT_INPUT_EXTENSIONS: &[&str] = &["enc", "locked", "fetched", "toml", "lock", "rs"];
+
+/// Input files of `days` in the directory tree `dir`, relative to it and sorted by path.
+///
+/// A file is an input of a day when a directory of its path or its own name without
+/// extension is `dayXY` or `dayX`, like `alice/day08/input` or `bob/day8.txt`, of the
+/// year of a `YYYY` directory of its path, `default_year` without one. Hidden files and
+/// directories are skipped, and so are the examples (`example-*`) and the files with an
+/// extension of [`NOT_INPUT_EXTENSIONS`], like `input.enc` or `rs/src/lib.rs` of a day
+/// crate.
+///
+/// # Errors
+/// When the tree cannot be read.
+pub fn inputs<'a>(
+    dir: &Path,
+    days: &[&'a Day],
+    default_year: u16,
+) -> io::Result<Vec<(&'a Day, PathBuf)>> {
+    let mut files = vec![];
+    walk(dir, &mut files)?;
+    files.sort();
+
+    Ok(files
+        .into_iter()
+        .filter_map(|path| {
+            let path = path.strip_prefix(dir).ok()?.to_path_buf();
+            let (year, day) = input_day(&path, default_year)?;
+            let day = days.iter().find(|d| (d.year, d.day) == (year, day))?;
+            Some((*day, path))
+        })
+        .collect())
+}
+
+fn walk(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+
+        let path = entry.path();
+        if path.is_dir() {
+            walk(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn input_day(path: &Path, default_year: u16) -> Option<(u16, u8)> {
+    let is_example = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with("example-"));
+    let is_not_input = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| NOT_INPUT_EXTENSIONS.contains(&extension));
+    if is_example || is_not_input {
+        return None;
+    }
+
+    let names = path
+        .parent()
+        .into_iter()
+        .flat_map(Path::components)
+        .map(Component::as_os_str)
+        .chain(path.file_stem());
+
+    let (mut year, mut day) = (None, None);
+    for name in names {
+        let Some(name) = name.to_str() else {
+            continue;
+        };
+        if name.len() == 4 && name.bytes().all(|c| c.is_ascii_digit()) {
+            year = name.parse().ok();
+        } else if let Some(number) = name.strip_prefix("day") {
+            day = number.parse().ok().or(day);
+        }
+    }
+
+    Some((year.unwrap_or(default_year), day?))
+}
+
+/// Outcome of the days on every input of a batch, shown as a table with the failures and
+/// the outliers.
+#[derive(Debug, Default)]
+pub struct Batch {
+    rows: Vec<(Day, PathBuf, Result<DayOutcome, Failure>)>,
+}
+
+impl Batch {
+    pub fn push(&mut self, day: &Day, path: PathBuf, outcome: Result<DayOutcome, Failure>) {
+        self.rows.push((*day, path, outcome));
+    }
+
+    /// Whether every input ran and no answer differs from the known one; outliers are
+    /// only flagged.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.rows.iter().all(|(_, _, outcome)| {
+            outcome
+                .as_ref()
+                .is_ok_and(|outcome| !outcome.is_regression())
+        })
+    }
+
+    /// Rows taking more than [`OUTLIER_FACTOR`] times the median time of their day, with
+    /// the median, for days with at least [`OUTLIER_MIN_INPUTS`] inputs that ran.
+    #[must_use]
+    pub fn outliers(&self) -> Vec<(usize, Duration)> {
+        let mut outliers = vec![];
+        for (index, (day, _, outcome)) in self.rows.iter().enumerate() {
+            let Ok(outcome) = outcome else {
+                continue;
+            };
+
+            let mut elapsed = self
+                .rows
+                .iter()
+                .filter(|(other, _, _)| (other.year, other.day) == (day.year, day.day))
+                .filter_map(|(_, _, outcome)| Some(outcome.as_ref().ok()?.elapsed()))
+                .collect::<Vec<_>>();
+            if elapsed.len() < OUTLIER_MIN_INPUTS {
+                continue;
+            }
+
+            elapsed.sort_unstable();
+            let median = elapsed[elapsed.len() / 2];
+            if outcome.elapsed() > median * OUTLIER_FACTOR {
+                outliers.push((index, median));
+            }
+        }
+        outliers
+    }
+}
+
+impl fmt::Display for Batch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = self
+            .rows
+            .iter()
+            .map(|(_, path, _)| path.display().to_string().len())
+            .max()
+            .unwrap_or(0)
+            .max("input".len());
+        let outliers = self.outliers();
+
+        writeln!(
+            f,
+            "{:<8} {:<width$} {:>20} {:>12} {:>20} {:>12} {:>8}",
+            "day", "input", "part 1", "time", "part 2", "time", "known"
+        )?;
+        let (mut panicked, mut timed_out, mut errors, mut mismatches) = (0, 0, 0, 0);
+        for (index, (day, path, outcome)) in self.rows.iter().enumerate() {
+            let (day, path) = (day.to_string(), path.display().to_string());
+            match outcome {
+                Ok(outcome) => {
+                    let (answer_2, elapsed_2) = outcome.part_2.as_ref().map_or_else(
+                        || ("-".to_string(), "-".to_string()),
+                        |part| (part.answer.clone(), format!("{:.3?}", part.elapsed)),
+                    );
+                    let outlier = if outliers.iter().any(|(outlier, _)| *outlier == index) {
+                        " outlier"
+                    } else {
+                        ""
+                    };
+                    writeln!(
+                        f,
+                        "{day:<8} {path:<width$} {:>20} {:>12} {answer_2:>20} {elapsed_2:>12} {:>8}{outlier}",
+                        outcome.part_1.answer,
+                        format!("{:.3?}", outcome.part_1.elapsed),
+                        outcome.known(),
+                    )?;
+                    for (part, outcome) in outcome.parts() {
+                        if let AnswerCheck::Mismatch { .. } = outcome.check {
+                            mismatches += 1;
+                            writeln!(f, "{:<8} {:<width$} part {part} {}", "", "", outcome.check)?;
+                        }
+                    }
+                }
+                Err(failure) => {
+                    match failure {
+                        Failure::Error(_) => errors += 1,
+                        Failure::Panicked(_) => panicked += 1,
+                        Failure::TimedOut(_) => timed_out += 1,
+                    }
+                    writeln!(f, "{day:<8} {path:<width$} {failure}")?;
+                }
+            }
+        }
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{} inputs, {panicked} panicked, {timed_out} timed out, {errors} errors, {mismatches} mismatches, {} outliers",
+            self.rows.len(),
+            outliers.len(),
+        )?;
+        for (index, median) in outliers {
+            let (day, path, outcome) = &self.rows[index];
+            if let Ok(outcome) = outcome {
+                writeln!(
+                    f,
+                    "{:<8} {} took {:.3?}, {:.1}x the median {median:.3?} of the day",
+                    day.to_string(),
+                    path.display(),
+                    outcome.elapsed(),
+                    outcome.elapsed().as_secs_f64() / median.as_secs_f64(),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{DAYS, PartOutcome};
+
+    fn outcome(micros: u64) -> DayOutcome {
+        DayOutcome {
+            sha256: String::new(),
+            part_1: PartOutcome {
+                answer: "1".to_string(),
+                elapsed: Duration::from_micros(micros),
+                stats: None,
+                spans: vec![],
+                check: AnswerCheck::Unknown,
+            },
+            part_2: None,
+        }
+    }
+
+    #[test]
+    fn test_input_day() {
+        assert_eq!(
+            input_day(Path::new("alice/day08/input"), 2025),
+            Some((2025, 8))
+        );
+        assert_eq!(input_day(Path::new("bob/day8.txt"), 2025), Some((2025, 8)));
+        assert_eq!(
+            input_day(Path::new("2024/carol/day01"), 2025),
+            Some((2024, 1))
+        );
+        assert_eq!(input_day(Path::new("day12/dayx"), 2025), Some((2025, 12)));
+        assert_eq!(input_day(Path::new("alice/input"), 2025), None);
+    }
+
+    #[test]
+    fn test_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc-run-{}-batch", std::process::id()));
+        for file in [
+            "alice/day01/input",
+            "alice/day02/input",
+            "bob/day1.txt",
+            "bob/.day1.txt",
+            "bob/notes",
+            "carol/day01/input.enc",
+            "carol/day01/input.locked",
+            "carol/day01/example-1-1",
+            "carol/day01/examples.fetched",
+            "carol/day01/inputs.toml",
+            "carol/day01/rs/Cargo.toml",
+            "carol/day01/rs/src/lib.rs",
+        ] {
+            let path = dir.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+
+        let inputs = inputs(&dir, &[&DAYS[0]], 2025).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            inputs
+                .iter()
+                .map(|(day, path)| (day.day, path.clone()))
+                .collect::<Vec<_>>(),
+            [
+                (1, PathBuf::from("alice/day01/input")),
+                (1, PathBuf::from("bob/day1.txt"))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_batch() {
+        let mut batch = Batch::default();
+        for (name, micros) in [("a", 10), ("b", 12), ("c", 11), ("d", 500)] {
+            batch.push(&DAYS[7], PathBuf::from(name), Ok(outcome(micros)));
+        }
+        batch.push(&DAYS[0], PathBuf::from("e"), Ok(outcome(1000)));
+        batch.push(
+            &DAYS[4],
+            PathBuf::from("f"),
+            Err(Failure::Panicked("index out of bounds".to_string())),
+        );
+
+        assert!(!batch.is_success());
+        assert_eq!(batch.outliers(), [(3, Duration::from_micros(12))]);
+
+        let text = batch.to_string();
+        let mut lines = text.lines();
+        assert!(lines.next().unwrap().starts_with("day      input "));
+        assert!(lines.nth(3).unwrap().ends_with(" outlier"));
+        assert!(!lines.next().unwrap().ends_with(" outlier"));
+        assert_eq!(
+            lines.next().unwrap(),
+            "2025-05  f     panicked: index out of bounds"
+        );
+        assert_eq!(
+            lines.nth(1).unwrap(),
+            "6 inputs, 1 panicked, 0 timed out, 0 errors, 0 mismatches, 1 outliers"
+        );
+        assert_eq!(
+            lines.next().unwrap(),
+            "2025-08  d took 500.000µs, 41.7x the median 12.000µs of the day"
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use std::any::Any;
+use std::io::{self, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use aoc::Answers;
+
+use report::Repeat;
+
+use crate::{Day, DayOutcome, Failure, Measured};
+
+impl Day {
+    /// Run like [`Day::run`] in a worker process, `program` run as `program --worker
+    /// <day>` with the repeat settings, turning a panic into [`Failure::Panicked`] and
+    /// killing the worker after `timeout`.
+    ///
+    /// The worker has its own threads and spans, so a day that times out leaves nothing
+    /// running behind to skew the timings of the next ones.
+    ///
+    /// # Errors
+    /// When the day panics, crashes, times out or the worker cannot be started.
+    pub fn run_isolated(
+        &self,
+        program: &Path,
+        input: String,
+        answers: &Answers,
+        repeat: &Repeat,
+        timeout: Duration,
+    ) -> Result<DayOutcome, Failure> {
+        let sha256 = aoc::sha256(input.as_bytes());
+
+        let mut child = Command::new(program)
+            .arg("--worker")
+            .arg(self.to_string())
+            .args(["--repeat", &repeat.budget.to_string()])
+            .args(["--warmup", &repeat.warmup.to_string()])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|err| Failure::Error(format!("cannot start worker: {err}")))?;
+
+        let (Some(mut stdin), Some(mut stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            return Err(Failure::Error("worker without pipes".to_string()));
+        };
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            // the worker reads its whole input before answering, a failed write shows up
+            // as a worker without outcome
+            let _ = stdin.write_all(input.as_bytes());
+            drop(stdin);
+
+            let mut output = String::new();
+            let _ = stdout.read_to_string(&mut output);
+            let _ = sender.send(output);
+        });
+
+        let Ok(output) = receiver.recv_timeout(timeout) else {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(Failure::TimedOut(timeout));
+        };
+        let status = child
+            .wait()
+            .map_err(|err| Failure::Error(err.to_string()))?;
+
+        match serde_json::from_str::<Result<Measured, String>>(&output) {
+            Ok(Ok(measured)) => Ok(self.check(sha256, answers, measured)),
+            Ok(Err(message)) => Err(Failure::Panicked(message)),
+            Err(_) => Err(Failure::Panicked(format!("worker {status}"))),
+        }
+    }
+}
+
+/// Worker of [`Day::run_isolated`]: run `day` on the input on stdin and write its
+/// answers with their timings, or the message of its panic, as JSON on stdout.
+///
+/// The panics of the day are not printed, the message is the outcome.
+///
+/// # Errors
+/// When stdin cannot be read or stdout cannot be written.
+pub fn work(day: &Day, repeat: &Repeat) -> io::Result<()> {
+    let input = io::read_to_string(io::stdin())?;
+
+    panic::set_hook(Box::new(|_| {}));
+    let measured = panic::catch_unwind(AssertUnwindSafe(|| day.measure(&input, repeat)))
+        .map_err(|payload| panic_message(&*payload));
+
+    serde_json::to_writer(io::stdout().lock(), &measured)?;
+    Ok(())
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
@@ -0,0 +1,550 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use aoc::{AnswerCheck, Answers, Config};
+
+use report::{Repeat, SpanTotal, Stats};
+
+use solution::Solution;
+
+mod batch;
+pub use batch::{Batch, OUTLIER_FACTOR, OUTLIER_MIN_INPUTS, inputs};
+
+mod isolate;
+pub use isolate::work;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Invalid day {0}, expected `[YYYY-]D`")]
+    InvalidDay(String),
+
+    #[error("Day {0} not in the workspace")]
+    UnknownDay(String),
+
+    #[error("Input given for {0} days, select a single day")]
+    SharedInput(usize),
+
+    #[error("No input of the selected days in {}", .0.display())]
+    NoInputs(PathBuf),
+
+    #[error(transparent)]
+    Aoc(#[from] aoc::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Wall-clock time an isolated day gets unless `--timeout` is given.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_mins(1);
+
+/// Day of the workspace with its solvers, answers formatted for display.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub part_1: fn(&str) -> String,
+    pub part_2: fn(&str) -> Option<String>,
+}
+
+fn part_1<S: Solution>(input: &str) -> String {
+    S::part_1(input).to_string()
+}
+
+fn part_2<S: Solution>(input: &str) -> Option<String> {
+    S::part_2(input).map(|answer| answer.to_string())
+}
+
+impl Day {
+    #[must_use]
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            part_1: part_1::<S>,
+            part_2: part_2::<S>,
+        }
+    }
+}
+
+/// Every day of the workspace.
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>(),
+    Day::new::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day11::Day11>(),
+    Day::new::<day12::Day12>(),
+];
+
+impl Day {
+    /// Default input of the day for the active profile of `config`, in the day folder
+    /// of the workspace in `root`.
+    #[must_use]
+    pub fn input_file(&self, config: &Config, root: &Path, workspace_year: i32) -> PathBuf {
+        let input_file = root
+            .join(aoc::day_dir(
+                workspace_year,
+                i32::from(self.year),
+                u32::from(self.day),
+            ))
+            .join("input");
+        PathBuf::from(config.input_file(&input_file.display().to_string()))
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{:02}", self.year, self.day)
+    }
+}
+
+/// Answer and running time of a part, the median with statistics when repeated, checked
+/// against the known answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartOutcome {
+    pub answer: String,
+    pub elapsed: Duration,
+    pub stats: Option<Stats>,
+    /// Spans of the part with the `tracing` feature.
+    pub spans: Vec<SpanTotal>,
+    pub check: AnswerCheck,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayOutcome {
+    /// SHA-256 of the input.
+    pub sha256: String,
+    pub part_1: PartOutcome,
+    pub part_2: Option<PartOutcome>,
+}
+
+impl DayOutcome {
+    /// Parts with their number.
+    pub fn parts(&self) -> impl Iterator<Item = (u32, &PartOutcome)> {
+        [(1, Some(&self.part_1)), (2, self.part_2.as_ref())]
+            .into_iter()
+            .filter_map(|(part, outcome)| Some((part, outcome?)))
+    }
+
+    /// Whether an answer differs from the known one.
+    #[must_use]
+    pub fn is_regression(&self) -> bool {
+        self.parts()
+            .any(|(_, part)| matches!(part.check, AnswerCheck::Mismatch { .. }))
+    }
+
+    /// State of the answers against the known ones: `mismatch`, `ok` when every answer
+    /// is known, `-` otherwise.
+    #[must_use]
+    pub fn known(&self) -> &'static str {
+        if self.is_regression() {
+            "mismatch"
+        } else if self
+            .parts()
+            .all(|(_, part)| part.check == AnswerCheck::Match)
+        {
+            "ok"
+        } else {
+            "-"
+        }
+    }
+
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.part_1.elapsed
+            + self
+                .part_2
+                .as_ref()
+                .map_or(Duration::ZERO, |part| part.elapsed)
+    }
+}
+
+/// Answer of a part with the statistics and spans of its runs, before its check.
+#[derive(Debug, Serialize, Deserialize)]
+struct Timed {
+    answer: String,
+    stats: Stats,
+    spans: Vec<SpanTotal>,
+}
+
+/// Answers of a day with their timings, sent back by the worker of an isolated day.
+#[derive(Debug, Serialize, Deserialize)]
+struct Measured {
+    part_1: Timed,
+    part_2: Option<Timed>,
+}
+
+impl Day {
+    /// Solve both parts, timing each one according to `repeat`, and check the answers
+    /// against the known answers of the input.
+    #[must_use]
+    pub fn run(&self, input: &str, answers: &Answers, repeat: &Repeat) -> DayOutcome {
+        let sha256 = aoc::sha256(input.as_bytes());
+        self.check(sha256, answers, self.measure(input, repeat))
+    }
+
+    fn measure(&self, input: &str, repeat: &Repeat) -> Measured {
+        let ((answer, stats), spans) = report::traced(|| repeat.measure(|| (self.part_1)(input)));
+        let part_1 = Timed {
+            answer,
+            stats,
+            spans,
+        };
+
+        let ((answer, stats), spans) = report::traced(|| repeat.measure(|| (self.part_2)(input)));
+        let part_2 = answer.map(|answer| Timed {
+            answer,
+            stats,
+            spans,
+        });
+
+        Measured { part_1, part_2 }
+    }
+
+    fn check(&self, sha256: String, answers: &Answers, measured: Measured) -> DayOutcome {
+        let (year, day) = (i32::from(self.year), u32::from(self.day));
+        let outcome = |part, timed: Timed| PartOutcome {
+            check: answers
+                .check(year, day, &sha256, part, &timed.answer)
+                .unwrap_or(AnswerCheck::Unknown),
+            answer: timed.answer,
+            elapsed: timed.stats.median,
+            stats: (timed.stats.runs > 1).then_some(timed.stats),
+            spans: timed.spans,
+        };
+
+        DayOutcome {
+            part_1: outcome(1, measured.part_1),
+            part_2: measured.part_2.map(|timed| outcome(2, timed)),
+            sha256,
+        }
+    }
+}
+
+/// Why a day has no outcome.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// The day did not run, like when its input is missing.
+    Error(String),
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::Error(err) => write!(f, "error: {err}"),
+            Failure::Panicked(message) => write!(f, "panicked: {message}"),
+            Failure::TimedOut(timeout) => write!(f, "timed out after {timeout:.3?}"),
+        }
+    }
+}
+
+/// Days matching `selection`, every day when empty.
+///
+/// Each entry is a `[YYYY-]D` day, `default_year` when the year is missing, or a year
+/// alone as `YYYY-`.
+///
+/// # Errors
+/// - [`Error::InvalidDay`] when an entry cannot be parsed
+/// - [`Error::UnknownDay`] when no day of the workspace matches an entry
+pub fn select<S: AsRef<str>>(selection: &[S], default_year: u16) -> Result<Vec<&'static Day>> {
+    if selection.is_empty() {
+        return Ok(DAYS.iter().collect());
+    }
+
+    let mut days = vec![];
+    for entry in selection {
+        let entry = entry.as_ref().trim();
+        let invalid = || Error::InvalidDay(entry.to_string());
+
+        let (year, day) = match entry.split_once('-') {
+            Some((year, "")) => (year.parse().map_err(|_| invalid())?, None),
+            Some((year, day)) => (
+                year.parse().map_err(|_| invalid())?,
+                Some(day.parse::<u8>().map_err(|_| invalid())?),
+            ),
+            None => (
+                default_year,
+                Some(entry.parse::<u8>().map_err(|_| invalid())?),
+            ),
+        };
+
+        let matching = DAYS
+            .iter()
+            .filter(|d| d.year == year && day.is_none_or(|day| d.day == day))
+            .collect::<Vec<_>>();
+        if matching.is_empty() {
+            return Err(Error::UnknownDay(entry.to_string()));
+        }
+
+        for d in matching {
+            if !days
+                .iter()
+                .any(|selected: &&Day| (selected.year, selected.day) == (d.year, d.day))
+            {
+                days.push(d);
+            }
+        }
+    }
+
+    Ok(days)
+}
+
+/// Outcome of every selected day, shown as a table with the total time.
+#[derive(Debug, Default)]
+pub struct Summary {
+    rows: Vec<(Day, std::result::Result<DayOutcome, Failure>)>,
+}
+
+impl Summary {
+    pub fn push(&mut self, day: &Day, outcome: std::result::Result<DayOutcome, Failure>) {
+        self.rows.push((*day, outcome));
+    }
+
+    /// Whether every day ran and no answer differs from the known one.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.rows.iter().all(|(_, outcome)| {
+            outcome
+                .as_ref()
+                .is_ok_and(|outcome| !outcome.is_regression())
+        })
+    }
+
+    /// Record the answers without a known answer, returning how many were added; answers
+    /// that differ from the known ones are left untouched.
+    pub fn record(&self, answers: &mut Answers) -> usize {
+        let mut recorded = 0;
+        for (day, outcome) in &self.rows {
+            let Ok(outcome) = outcome else {
+                continue;
+            };
+            for (part, part_outcome) in outcome.parts() {
+                if part_outcome.check == AnswerCheck::Unknown
+                    && answers
+                        .insert(
+                            i32::from(day.year),
+                            u32::from(day.day),
+                            &outcome.sha256,
+                            part,
+                            &part_outcome.answer,
+                        )
+                        .is_ok()
+                {
+                    recorded += 1;
+                }
+            }
+        }
+        recorded
+    }
+
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.rows
+            .iter()
+            .filter_map(|(_, outcome)| outcome.as_ref().ok())
+            .map(DayOutcome::elapsed)
+            .sum()
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<8} {:>20} {:>12} {:>20} {:>12} {:>8}",
+            "day", "part 1", "time", "part 2", "time", "known"
+        )?;
+        for (day, outcome) in &self.rows {
+            let day = day.to_string();
+            match outcome {
+                Ok(outcome) => {
+                    let (answer_2, elapsed_2) = outcome.part_2.as_ref().map_or_else(
+                        || ("-".to_string(), "-".to_string()),
+                        |part| (part.answer.clone(), format!("{:.3?}", part.elapsed)),
+                    );
+                    writeln!(
+                        f,
+                        "{day:<8} {:>20} {:>12} {answer_2:>20} {elapsed_2:>12} {:>8}",
+                        outcome.part_1.answer,
+                        format!("{:.3?}", outcome.part_1.elapsed),
+                        outcome.known(),
+                    )?;
+                    for (part, outcome) in outcome.parts() {
+                        if let AnswerCheck::Mismatch { .. } = outcome.check {
+                            writeln!(f, "{:<8} part {part} {}", "", outcome.check)?;
+                        }
+                    }
+                }
+                Err(failure) => writeln!(f, "{day:<8} {failure}")?,
+            }
+        }
+        writeln!(
+            f,
+            "{:<63} {:>12}",
+            "total",
+            format!("{:.3?}", self.elapsed())
+        )?;
+
+        let repeated = self
+            .rows
+            .iter()
+            .filter_map(|(day, outcome)| Some((day, outcome.as_ref().ok()?)))
+            .flat_map(|(day, outcome)| {
+                outcome
+                    .parts()
+                    .filter_map(move |(part, outcome)| Some((day, part, outcome.stats?)))
+            })
+            .collect::<Vec<_>>();
+        if !repeated.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "{:<8} {:>4} {}", "day", "part", Stats::header())?;
+            for (day, part, stats) in repeated {
+                writeln!(f, "{:<8} {part:>4} {stats}", day.to_string())?;
+            }
+        }
+
+        let traced = self
+            .rows
+            .iter()
+            .filter_map(|(day, outcome)| Some((day, outcome.as_ref().ok()?)))
+            .flat_map(|(day, outcome)| {
+                outcome.parts().flat_map(move |(part, outcome)| {
+                    outcome.spans.iter().map(move |span| (day, part, span))
+                })
+            })
+            .collect::<Vec<_>>();
+        if !traced.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "{:<8} {:>4} {}", "day", "part", SpanTotal::header())?;
+            for (day, part, span) in traced {
+                writeln!(f, "{:<8} {part:>4} {span}", day.to_string())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select() {
+        assert_eq!(select::<&str>(&[], 2025).unwrap().len(), DAYS.len());
+
+        let days = select(&["1", "2025-03", "1"], 2025).unwrap();
+        assert_eq!(
+            days.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec!["2025-01", "2025-03"]
+        );
+
+        assert_eq!(select(&["2025-"], 2025).unwrap().len(), 12);
+
+        assert!(matches!(select(&["x"], 2025), Err(Error::InvalidDay(_))));
+        assert!(matches!(select(&["99"], 2025), Err(Error::UnknownDay(_))));
+        assert!(matches!(select(&["1"], 2015), Err(Error::UnknownDay(_))));
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut summary = Summary::default();
+        summary.push(
+            &DAYS[11],
+            Ok(DayOutcome {
+                sha256: "abc".to_string(),
+                part_1: PartOutcome {
+                    answer: "42".to_string(),
+                    elapsed: Duration::from_millis(2),
+                    stats: None,
+                    spans: vec![SpanTotal {
+                        name: "regions".into(),
+                        calls: 1,
+                        total: Duration::from_millis(1),
+                    }],
+                    check: AnswerCheck::Unknown,
+                },
+                part_2: None,
+            }),
+        );
+        summary.push(&DAYS[0], Err(Failure::Error("input missing".to_string())));
+
+        assert!(!summary.is_success());
+        assert_eq!(summary.elapsed(), Duration::from_millis(2));
+
+        let text = summary.to_string();
+        assert!(text.contains("2025-12"));
+        assert!(text.contains("2025-01  error: input missing"));
+        assert!(
+            text.lines()
+                .any(|line| line.starts_with("total") && line.ends_with("2.000ms"))
+        );
+        assert!(text.contains("\nday      part span            calls"));
+        assert!(
+            text.lines()
+                .last()
+                .unwrap()
+                .starts_with("2025-12     1 regions ")
+        );
+
+        let mut answers = Answers::default();
+        assert_eq!(summary.record(&mut answers), 1);
+        assert_eq!(
+            answers.check(2025, 12, "abc", 1, "42").unwrap(),
+            AnswerCheck::Match
+        );
+    }
+
+    #[test]
+    fn test_known_answers() {
+        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+        let sha256 = aoc::sha256(input.as_bytes());
+
+        let mut answers = Answers::default();
+        answers.insert(2025, 1, &sha256, 1, "3").unwrap();
+        answers.insert(2025, 1, &sha256, 2, "7").unwrap();
+
+        let outcome = DAYS[0].run(input, &answers, &Repeat::default());
+        assert_eq!(outcome.part_1.check, AnswerCheck::Match);
+        assert_eq!(
+            outcome.part_2.as_ref().unwrap().check,
+            AnswerCheck::Mismatch {
+                expected: "7".to_string()
+            }
+        );
+        assert!(outcome.is_regression());
+
+        let mut summary = Summary::default();
+        summary.push(&DAYS[0], Ok(outcome));
+        assert!(!summary.is_success());
+        assert_eq!(summary.record(&mut answers), 0);
+        assert!(summary.to_string().contains("part 2 expected 7"));
+    }
+
+    #[test]
+    fn test_repeat() {
+        let repeat = Repeat {
+            warmup: 1,
+            budget: report::Budget::Iterations(3),
+        };
+        let outcome = DAYS[0].run("L68\nR48\n", &Answers::default(), &repeat);
+        assert_eq!(outcome.part_1.stats.unwrap().runs, 3);
+        assert_eq!(outcome.part_1.elapsed, outcome.part_1.stats.unwrap().median);
+
+        let mut summary = Summary::default();
+        summary.push(&DAYS[0], Ok(outcome));
+        let text = summary.to_string();
+        assert!(text.contains("\nday      part     runs "));
+        assert!(text.contains("\n2025-01     1        3 "));
+        assert!(text.contains("\n2025-01     2        3 "));
+    }
+}
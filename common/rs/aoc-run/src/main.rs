@@ -0,0 +1,260 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::Parser;
+
+use aoc::{Answers, Config};
+
+use aoc_run::{Batch, Day, Error, Failure, Result, Summary};
+
+use report::{Budget, Repeat};
+
+#[derive(Parser)]
+#[command(about = "Run the days of the workspace and show their answers and timings")]
+struct Cli {
+    /// Days to run as `[YYYY-]D`, or `YYYY-` for a whole event; every day by default
+    days: Vec<String>,
+
+    /// Input file of a single day, `-` for stdin; by default the input of the day
+    /// folder for the active profile
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Run the days on every input in the directory tree, isolated: a file is an input
+    /// of a day when a directory of its path or its name is `dayXY`, of the year of a
+    /// `YYYY` directory of its path or of the workspace
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["input", "record"])]
+    batch: Option<PathBuf>,
+
+    /// Record the answers without a known answer in the answers file of the workspace,
+    /// once confirmed; answers that differ from the known ones are never replaced
+    #[arg(long)]
+    record: bool,
+
+    /// Time each part over several runs, as an iteration count like `100` or a time
+    /// budget like `500ms` or `2s`, and show min, median, mean and p95
+    #[arg(long, value_name = "N|TIME")]
+    repeat: Option<Budget>,
+
+    /// Untimed runs of each part before the timed ones, 3 with `--repeat` by default
+    #[arg(long, value_name = "N")]
+    warmup: Option<u32>,
+
+    /// Run each day in a worker process of its own, reporting a panic or a timeout as
+    /// the outcome of the day instead of stopping the run
+    #[arg(long)]
+    isolate: bool,
+
+    /// Wall-clock time of each isolated day, like `500ms` or `10s`, 60s by default;
+    /// implies `--isolate`
+    #[arg(long, value_name = "TIME", value_parser = report::parse_time)]
+    timeout: Option<Duration>,
+
+    /// Run a single day on stdin as the worker process of an isolated day
+    #[arg(long, value_name = "DAY", hide = true)]
+    worker: Option<String>,
+}
+
+impl Cli {
+    fn repeat(&self) -> Repeat {
+        Repeat {
+            warmup: self.warmup.unwrap_or(if self.repeat.is_some() {
+                report::DEFAULT_WARMUP
+            } else {
+                0
+            }),
+            budget: self.repeat.unwrap_or_default(),
+        }
+    }
+}
+
+enum Input {
+    File(PathBuf),
+    Stdin,
+    Default { root: PathBuf, workspace_year: i32 },
+}
+
+impl Input {
+    fn read(&self, config: &Config, day: &Day) -> Result<String> {
+        match self {
+            Input::File(path) => Ok(fs::read_to_string(path).map_err(aoc::Error::from)?),
+            Input::Stdin => Ok(io::read_to_string(io::stdin()).map_err(aoc::Error::from)?),
+            Input::Default {
+                root,
+                workspace_year,
+            } => {
+                let input_file = day.input_file(config, root, *workspace_year);
+
+                aoc::try_get_input_with(
+                    config,
+                    i32::from(day.year),
+                    u32::from(day.day),
+                    &input_file.display().to_string(),
+                )?;
+
+                Ok(fs::read_to_string(input_file).map_err(aoc::Error::from)?)
+            }
+        }
+    }
+}
+
+enum Outcome {
+    Summary(Summary),
+    Batch(Batch),
+}
+
+impl Outcome {
+    fn is_success(&self) -> bool {
+        match self {
+            Outcome::Summary(summary) => summary.is_success(),
+            Outcome::Batch(batch) => batch.is_success(),
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Summary(summary) => summary.fmt(f),
+            Outcome::Batch(batch) => batch.fmt(f),
+        }
+    }
+}
+
+fn batch(
+    program: &Path,
+    dir: &Path,
+    days: &[&Day],
+    default_year: u16,
+    answers: &Answers,
+    repeat: &Repeat,
+    timeout: Duration,
+) -> Result<Batch> {
+    let inputs = aoc_run::inputs(dir, days, default_year).map_err(aoc::Error::from)?;
+    if inputs.is_empty() {
+        return Err(Error::NoInputs(dir.to_path_buf()));
+    }
+
+    let mut batch = Batch::default();
+    for (day, path) in inputs {
+        let outcome = fs::read_to_string(dir.join(&path))
+            .map_err(|err| Failure::Error(err.to_string()))
+            .and_then(|input| day.run_isolated(program, input, answers, repeat, timeout));
+        batch.push(day, path, outcome);
+    }
+    Ok(batch)
+}
+
+fn run(cli: Cli) -> Result<Outcome> {
+    let mut config = Config::from_env();
+    config.fetch = env::var(aoc::FETCH_ENV).is_ok_and(|fetch| fetch == "1");
+
+    let root = aoc::workspace_root()?;
+    let workspace_year = aoc::workspace_year(&root)?;
+
+    let answers_file = aoc::answers_file(&root);
+    let mut answers = Answers::load(&answers_file)?;
+
+    let default_year = workspace_year
+        .try_into()
+        .map_err(|_| aoc::Error::Metadata("invalid workspace year"))?;
+    let days = aoc_run::select(&cli.days, default_year)?;
+
+    let repeat = cli.repeat();
+
+    // isolated days run in a worker process of this same binary
+    let program = env::current_exe().map_err(aoc::Error::from)?;
+
+    let timeout = cli
+        .timeout
+        .or(cli.isolate.then_some(aoc_run::DEFAULT_TIMEOUT));
+
+    if let Some(dir) = &cli.batch {
+        let timeout = timeout.unwrap_or(aoc_run::DEFAULT_TIMEOUT);
+        return batch(
+            &program,
+            dir,
+            &days,
+            default_year,
+            &answers,
+            &repeat,
+            timeout,
+        )
+        .map(Outcome::Batch);
+    }
+
+    let input = match cli.input {
+        Some(_) if days.len() > 1 => return Err(Error::SharedInput(days.len())),
+        Some(path) if path.as_os_str() == "-" => Input::Stdin,
+        Some(path) => Input::File(path),
+        None => Input::Default {
+            root,
+            workspace_year,
+        },
+    };
+
+    let mut summary = Summary::default();
+    for day in days {
+        let outcome = input
+            .read(&config, day)
+            .map_err(|err| Failure::Error(err.to_string()))
+            .and_then(|input| match timeout {
+                Some(timeout) => day.run_isolated(&program, input, &answers, &repeat, timeout),
+                None => Ok(day.run(&input, &answers, &repeat)),
+            });
+        summary.push(day, outcome);
+    }
+
+    if cli.record {
+        let recorded = summary.record(&mut answers);
+        answers.save(&answers_file)?;
+        eprintln!(
+            "recorded {recorded} new answers in {}",
+            answers_file.display()
+        );
+    }
+
+    Ok(Outcome::Summary(summary))
+}
+
+fn worker(day: &str, repeat: &Repeat) -> ExitCode {
+    let Some(day) = aoc_run::DAYS.iter().find(|d| d.to_string() == day) else {
+        eprintln!("error: {}", Error::UnknownDay(day.to_string()));
+        return ExitCode::FAILURE;
+    };
+
+    match aoc_run::work(day, repeat) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Some(day) = &cli.worker {
+        return worker(day, &cli.repeat());
+    }
+
+    match run(cli) {
+        Ok(outcome) => {
+            print!("{outcome}");
+            if outcome.is_success() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use std::fs;
+use std::path::Path;
+
+use aoc::{AnswerCheck, Answers, Config};
+
+use report::Repeat;
+
+/// Every input of the workspace with known answers still gives them.
+#[test]
+fn test_known_answers() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../..");
+    let workspace_year = aoc::workspace_year(&root).unwrap();
+    let answers = Answers::load(aoc::answers_file(&root)).unwrap();
+    let config = Config::from_env();
+
+    let mut regressions = vec![];
+    for day in aoc_run::DAYS {
+        let Ok(input) = fs::read_to_string(day.input_file(&config, &root, workspace_year)) else {
+            continue;
+        };
+
+        let sha256 = aoc::sha256(input.as_bytes());
+        if answers
+            .get(i32::from(day.year), u32::from(day.day), &sha256)
+            .is_none()
+        {
+            continue;
+        }
+
+        let outcome = day.run(&input, &answers, &Repeat::default());
+        for (part, outcome) in outcome.parts() {
+            if let AnswerCheck::Mismatch { expected } = &outcome.check {
+                regressions.push(format!(
+                    "{day} part {part}: {}, expected {expected}",
+                    outcome.answer
+                ));
+            }
+        }
+    }
+
+    assert!(regressions.is_empty(), "{}", regressions.join("\n"));
+}
@@ -0,0 +1,57 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use aoc::Answers;
+
+use aoc_run::{DAYS, Failure, Summary};
+
+use report::{Budget, Repeat};
+
+const INPUT: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+
+fn run(input: &str, repeat: &Repeat) -> Result<aoc_run::DayOutcome, Failure> {
+    DAYS[0].run_isolated(
+        Path::new(env!("CARGO_BIN_EXE_aoc-run")),
+        input.to_string(),
+        &Answers::default(),
+        repeat,
+        Duration::from_secs(2),
+    )
+}
+
+#[test]
+fn test_run_isolated() {
+    let outcome = run(INPUT, &Repeat::default()).unwrap();
+    assert_eq!(outcome.part_1.answer, "3");
+    assert_eq!(outcome.part_2.unwrap().answer, "6");
+
+    let repeat = Repeat {
+        warmup: 1,
+        budget: Budget::Iterations(3),
+    };
+    assert_eq!(run(INPUT, &repeat).unwrap().part_1.stats.unwrap().runs, 3);
+
+    let failure = run("X1\n", &Repeat::default()).unwrap_err();
+    assert!(matches!(&failure, Failure::Panicked(message) if message.contains("unreachable")));
+
+    let mut summary = Summary::default();
+    summary.push(&DAYS[0], Err(failure));
+    assert!(!summary.is_success());
+    assert!(summary.to_string().contains("\n2025-01  panicked: "));
+}
+
+#[test]
+fn test_run_isolated_timeout() {
+    let repeat = Repeat {
+        warmup: 0,
+        budget: Budget::Iterations(u32::MAX),
+    };
+
+    let now = Instant::now();
+    assert_eq!(
+        run(INPUT, &repeat).unwrap_err(),
+        Failure::TimedOut(Duration::from_secs(2))
+    );
+    // the worker is killed instead of running out its budget
+    assert!(now.elapsed() < Duration::from_secs(10));
+}
@@ -0,0 +1,313 @@
+//! JavaScript API of the solvers of the days, built with wasm-pack into a package with its
+//! TypeScript declarations, for any frontend or Node script.
+//!
+//! `solve(day, part, input)` returns the answer as a string, `undefined` for a day
+//! without part 2, and `time` and `bench` time it with `performance.now()`. A day or a
+//! part that does not exist throws a `SolveError` of its `ErrorKind`; so does a panic of
+//! a solver when the package is built with unwinding panics, the default `panic=abort`
+//! of wasm traps on it instead.
+
+use std::any::Any;
+use std::fmt;
+use std::panic;
+use std::time::Duration;
+
+use instant::Instant;
+
+use wasm_bindgen::prelude::*;
+
+use report::Stats;
+use solution::Solution;
+
+/// Solver of a part, `None` for a day without part 2.
+type Part = fn(&str) -> Option<String>;
+
+#[allow(clippy::unnecessary_wraps)]
+fn part_1<S: Solution>(input: &str) -> Option<String> {
+    Some(S::part_1(input).to_string())
+}
+
+fn part_2<S: Solution>(input: &str) -> Option<String> {
+    S::part_2(input).map(|answer| answer.to_string())
+}
+
+const fn day<S: Solution>() -> (u8, Part, Part) {
+    (S::DAY, part_1::<S>, part_2::<S>)
+}
+
+/// Days of the workspace event, with the default variant of the parameterized ones.
+const DAYS: &[(u8, Part, Part)] = &[
+    day::<day01::Day01>(),
+    day::<day02::Day02>(),
+    day::<day03::Day03>(),
+    day::<day04::Day04>(),
+    day::<day05::Day05>(),
+    day::<day06::Day06>(),
+    day::<day07::Day07>(),
+    day::<day08::Day08>(),
+    day::<day09::Day09>(),
+    day::<day10::Day10>(),
+    day::<day11::Day11>(),
+    day::<day12::Day12>(),
+];
+
+/// Kind of a [`SolveError`].
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    UnsupportedDay,
+    InvalidPart,
+    /// The solver panicked, like on an unexpected input.
+    Panicked,
+}
+
+/// Error thrown to JavaScript instead of a trap.
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    kind: ErrorKind,
+    message: String,
+}
+
+#[wasm_bindgen]
+impl SolveError {
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    #[must_use]
+    pub fn js_to_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SolveError: {}", self.message)
+    }
+}
+
+fn find(day: u32, part: u32) -> Result<Part, SolveError> {
+    let (_, part_1, part_2) = DAYS
+        .iter()
+        .find(|(d, _, _)| u32::from(*d) == day)
+        .ok_or_else(|| SolveError {
+            kind: ErrorKind::UnsupportedDay,
+            message: format!("unsupported day {day}"),
+        })?;
+    match part {
+        1 => Ok(*part_1),
+        2 => Ok(*part_2),
+        _ => Err(SolveError {
+            kind: ErrorKind::InvalidPart,
+            message: format!("invalid part {part}, expected 1 or 2"),
+        }),
+    }
+}
+
+/// Run `part` turning a panic into a [`SolveError`], with unwinding panics only.
+fn guarded(part: Part, input: &str) -> Result<Option<String>, SolveError> {
+    panic::catch_unwind(|| part(input)).map_err(|payload| SolveError {
+        kind: ErrorKind::Panicked,
+        message: panic_message(&*payload),
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1e3
+}
+
+/// Days of the workspace event.
+#[wasm_bindgen]
+#[must_use]
+pub fn days() -> Vec<u8> {
+    DAYS.iter().map(|(day, _, _)| *day).collect()
+}
+
+/// Answer of `part` of `day` of the workspace event, `undefined` for a day without part 2.
+///
+/// # Errors
+/// A [`SolveError`] for a day or a part that does not exist, or a panic of the solver.
+#[wasm_bindgen]
+pub fn solve(day: u32, part: u32, input: &str) -> Result<Option<String>, SolveError> {
+    guarded(find(day, part)?, input)
+}
+
+/// Answer of a part with the time of a single run.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Timing {
+    answer: Option<String>,
+    elapsed: Duration,
+}
+
+#[wasm_bindgen]
+impl Timing {
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn answer(&self) -> Option<String> {
+        self.answer.clone()
+    }
+
+    #[wasm_bindgen(getter = elapsedMs)]
+    #[must_use]
+    pub fn elapsed_ms(&self) -> f64 {
+        millis(self.elapsed)
+    }
+}
+
+/// [`solve`] timed over a single run.
+///
+/// # Errors
+/// As [`solve`].
+#[wasm_bindgen]
+pub fn time(day: u32, part: u32, input: &str) -> Result<Timing, SolveError> {
+    let part = find(day, part)?;
+
+    let now = Instant::now();
+    let answer = guarded(part, input)?;
+    Ok(Timing {
+        answer,
+        elapsed: now.elapsed(),
+    })
+}
+
+/// Answer of a part with the statistics of the timed runs.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Bench {
+    answer: Option<String>,
+    stats: Stats,
+}
+
+#[wasm_bindgen]
+impl Bench {
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn answer(&self) -> Option<String> {
+        self.answer.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn runs(&self) -> u32 {
+        self.stats.runs
+    }
+
+    #[wasm_bindgen(getter = minMs)]
+    #[must_use]
+    pub fn min_ms(&self) -> f64 {
+        millis(self.stats.min)
+    }
+
+    #[wasm_bindgen(getter = medianMs)]
+    #[must_use]
+    pub fn median_ms(&self) -> f64 {
+        millis(self.stats.median)
+    }
+
+    #[wasm_bindgen(getter = meanMs)]
+    #[must_use]
+    pub fn mean_ms(&self) -> f64 {
+        millis(self.stats.mean)
+    }
+
+    /// 95th percentile, nearest rank.
+    #[wasm_bindgen(getter = p95Ms)]
+    #[must_use]
+    pub fn p95_ms(&self) -> f64 {
+        millis(self.stats.p95)
+    }
+}
+
+/// [`solve`] timed over `runs` runs, at least one, after `warmup` untimed runs, 3 by
+/// default.
+///
+/// # Errors
+/// As [`solve`].
+#[wasm_bindgen]
+pub fn bench(
+    day: u32,
+    part: u32,
+    input: &str,
+    runs: u32,
+    warmup: Option<u32>,
+) -> Result<Bench, SolveError> {
+    let part = find(day, part)?;
+
+    for _ in 0..warmup.unwrap_or(report::DEFAULT_WARMUP) {
+        guarded(part, input)?;
+    }
+
+    let mut samples = vec![];
+    let mut answer = None;
+    for _ in 0..runs.max(1) {
+        let now = Instant::now();
+        answer = guarded(part, input)?;
+        samples.push(now.elapsed());
+    }
+
+    Ok(Bench {
+        answer,
+        stats: Stats::new(&mut samples),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(days(), (1..=12).collect::<Vec<_>>());
+        assert_eq!(solve(1, 1, INPUT), Ok(Some("3".to_string())));
+        assert_eq!(solve(1, 2, INPUT), Ok(Some("6".to_string())));
+        assert_eq!(solve(12, 2, ""), Ok(None));
+    }
+
+    #[test]
+    fn test_errors() {
+        let kind = |day, part, input| solve(day, part, input).unwrap_err().kind();
+
+        assert_eq!(kind(26, 1, INPUT), ErrorKind::UnsupportedDay);
+        assert_eq!(kind(1, 3, INPUT), ErrorKind::InvalidPart);
+        assert_eq!(kind(1, 1, "X1\n"), ErrorKind::Panicked);
+        assert_eq!(
+            solve(1, 3, INPUT).unwrap_err().to_string(),
+            "SolveError: invalid part 3, expected 1 or 2"
+        );
+    }
+
+    #[test]
+    fn test_timing() {
+        let timing = time(1, 1, INPUT).unwrap();
+        assert_eq!(timing.answer(), Some("3".to_string()));
+        assert!(timing.elapsed_ms() >= 0.);
+
+        let bench = bench(1, 2, INPUT, 5, Some(0)).unwrap();
+        assert_eq!(bench.answer(), Some("6".to_string()));
+        assert_eq!(bench.runs(), 5);
+        assert!(bench.min_ms() <= bench.median_ms() && bench.median_ms() <= bench.p95_ms());
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result, write_if_changed};
+
+/// File name of the known answers, in the workspace root.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Confirmed answers of an input.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_2: Option<String>,
+}
+
+impl KnownAnswers {
+    fn part(&self, part: u32) -> Result<Option<&str>> {
+        match part {
+            1 => Ok(self.part_1.as_deref()),
+            2 => Ok(self.part_2.as_deref()),
+            _ => Err(Error::InvalidPart(part)),
+        }
+    }
+
+    fn part_mut(&mut self, part: u32) -> Result<&mut Option<String>> {
+        match part {
+            1 => Ok(&mut self.part_1),
+            2 => Ok(&mut self.part_2),
+            _ => Err(Error::InvalidPart(part)),
+        }
+    }
+}
+
+/// Known answers by day, as `YYYY-DD`, then by SHA-256 of the input.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, BTreeMap<String, KnownAnswers>>,
+}
+
+fn day_key(year: i32, day: u32) -> String {
+    format!("{year}-{day:02}")
+}
+
+impl Answers {
+    /// # Errors
+    /// - [`Error::Metadata`] when the file is not valid TOML
+    /// - [`Error::Io`] when the file exists but cannot be read
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                toml::from_str(&content).map_err(|_| Error::Metadata("invalid answers file"))
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// # Errors
+    /// - [`Error::Io`] when the file cannot be written
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let current = fs::read_to_string(path).ok();
+        let content = toml::to_string(self).map_err(|_| Error::Metadata("invalid answers file"))?;
+        write_if_changed(path, current.as_deref(), &content)
+    }
+
+    #[must_use]
+    pub fn get(&self, year: i32, day: u32, sha256: &str) -> Option<&KnownAnswers> {
+        self.days.get(&day_key(year, day))?.get(sha256)
+    }
+
+    /// Compare `answer` of `part` with the known one.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidPart`] when `part` is neither 1 nor 2
+    pub fn check(
+        &self,
+        year: i32,
+        day: u32,
+        sha256: &str,
+        part: u32,
+        answer: &str,
+    ) -> Result<AnswerCheck> {
+        let expected = match self.get(year, day, sha256) {
+            Some(known) => known.part(part)?,
+            None if matches!(part, 1 | 2) => None,
+            None => return Err(Error::InvalidPart(part)),
+        };
+
+        Ok(match expected {
+            None => AnswerCheck::Unknown,
+            Some(expected) if expected == answer => AnswerCheck::Match,
+            Some(expected) => AnswerCheck::Mismatch {
+                expected: expected.to_string(),
+            },
+        })
+    }
+
+    /// Record `answer` of `part`, replacing the known one.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidPart`] when `part` is neither 1 nor 2
+    pub fn insert(
+        &mut self,
+        year: i32,
+        day: u32,
+        sha256: &str,
+        part: u32,
+        answer: impl Into<String>,
+    ) -> Result<()> {
+        *self
+            .days
+            .entry(day_key(year, day))
+            .or_default()
+            .entry(sha256.to_string())
+            .or_default()
+            .part_mut(part)? = Some(answer.into());
+        Ok(())
+    }
+}
+
+/// Result of [`Answers::check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnswerCheck {
+    /// No known answer for the part of the input.
+    Unknown,
+    Match,
+    Mismatch {
+        expected: String,
+    },
+}
+
+impl fmt::Display for AnswerCheck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnswerCheck::Unknown => write!(f, "unknown"),
+            AnswerCheck::Match => write!(f, "ok"),
+            AnswerCheck::Mismatch { expected } => write!(f, "expected {expected}"),
+        }
+    }
+}
+
+/// Path of the known answers of the workspace in `root`.
+#[must_use]
+pub fn answers_file(root: impl AsRef<Path>) -> PathBuf {
+    root.as_ref().join(ANSWERS_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers() {
+        let mut answers = Answers::default();
+        answers.insert(2025, 1, "abc", 1, "42").unwrap();
+        answers.insert(2025, 1, "abc", 2, "43").unwrap();
+        answers.insert(2025, 12, "def", 1, "7").unwrap();
+        assert!(matches!(
+            answers.insert(2025, 1, "abc", 3, "0"),
+            Err(Error::InvalidPart(3))
+        ));
+
+        assert_eq!(
+            answers.check(2025, 1, "abc", 1, "42").unwrap(),
+            AnswerCheck::Match
+        );
+        assert_eq!(
+            answers.check(2025, 1, "abc", 2, "42").unwrap(),
+            AnswerCheck::Mismatch {
+                expected: "43".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(2025, 12, "def", 2, "1").unwrap(),
+            AnswerCheck::Unknown
+        );
+        assert_eq!(
+            answers.check(2025, 1, "def", 1, "42").unwrap(),
+            AnswerCheck::Unknown
+        );
+        assert!(answers.check(2025, 2, "abc", 0, "1").is_err());
+
+        let content = toml::to_string(&answers).unwrap();
+        assert!(content.contains("[2025-01.abc]\npart_1 = \"42\"\npart_2 = \"43\"\n"));
+        assert_eq!(toml::from_str::<Answers>(&content).unwrap(), answers);
+    }
+}
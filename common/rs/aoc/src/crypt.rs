@@ -0,0 +1,165 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aes::Aes256;
+use aes::cipher::{
+    BlockDecryptMut, BlockEncrypt, BlockEncryptMut, KeyInit, KeyIvInit, block_padding::Pkcs7,
+    generic_array::GenericArray,
+};
+
+use dirs::config_dir;
+
+use crate::{Config, Error, Result, write_if_changed};
+
+type Encryptor = cbc::Encryptor<Aes256>;
+type Decryptor = cbc::Decryptor<Aes256>;
+
+pub type Key = [u8; 32];
+
+const IV_SIZE: usize = 16;
+
+/// Path of the encrypted version of an input file, e.g. `input.enc`.
+#[must_use]
+pub fn encrypted_file(input_file: impl AsRef<Path>) -> PathBuf {
+    let mut file = input_file.as_ref().as_os_str().to_owned();
+    file.push(".enc");
+    PathBuf::from(file)
+}
+
+/// Read the hex encoded AES-256 key, `adventofcode.key` in the config dir by default.
+///
+/// # Errors
+/// - [`Error::KeyMissing`] when the key file does not exist or is not a valid key
+/// - [`Error::Io`] when the key file cannot be read
+pub fn read_key(config: &Config) -> Result<Key> {
+    let key_file = match &config.key_file {
+        Some(key_file) => key_file.clone(),
+        None => config_dir()
+            .ok_or(Error::KeyMissing("cannot find config dir".to_string()))?
+            .join("adventofcode.key"),
+    };
+
+    let key = fs::read_to_string(&key_file).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => Error::KeyMissing(key_file.display().to_string()),
+        _ => Error::Io(err),
+    })?;
+
+    let mut result = [0; 32];
+    hex::decode_to_slice(key.trim(), &mut result)
+        .map_err(|_| Error::KeyMissing(format!("invalid key in {}", key_file.display())))?;
+
+    Ok(result)
+}
+
+/// CBC-MAC of `data` under a key derived from `key`, used as a synthetic IV so that
+/// the same input always encrypts to the same file and a wrong key is detected.
+fn synthetic_iv(key: &Key, data: &[u8]) -> [u8; IV_SIZE] {
+    let cipher = Aes256::new(key.into());
+    let mut mac_key = [0; 32];
+    for (i, chunk) in mac_key.chunks_mut(IV_SIZE).enumerate() {
+        let mut block = GenericArray::from([u8::try_from(i).unwrap() | 0x80; IV_SIZE]);
+        cipher.encrypt_block(&mut block);
+        chunk.copy_from_slice(&block);
+    }
+
+    let mac =
+        Encryptor::new(&mac_key.into(), &[0; IV_SIZE].into()).encrypt_padded_vec_mut::<Pkcs7>(data);
+
+    let mut iv = [0; IV_SIZE];
+    iv.copy_from_slice(&mac[mac.len() - IV_SIZE..]);
+    iv
+}
+
+/// Encrypt `data` as hex encoded IV followed by the ciphertext.
+#[must_use]
+pub fn encrypt(key: &Key, data: &[u8]) -> String {
+    let iv = synthetic_iv(key, data);
+    let ciphertext = Encryptor::new(key.into(), &iv.into()).encrypt_padded_vec_mut::<Pkcs7>(data);
+
+    let mut result = hex::encode(iv);
+    result.push_str(&hex::encode(ciphertext));
+    result.push('\n');
+    result
+}
+
+/// # Errors
+/// - [`Error::Decrypt`] when `data` is corrupted or `key` is not the right key
+pub fn decrypt(key: &Key, data: &str) -> Result<Vec<u8>> {
+    let data = hex::decode(data.trim()).map_err(|_| Error::Decrypt)?;
+    if data.len() < 2 * IV_SIZE {
+        return Err(Error::Decrypt);
+    }
+
+    let (iv, ciphertext) = data.split_at(IV_SIZE);
+    let plaintext = Decryptor::new(key.into(), iv.into())
+        .decrypt_padded_vec_mut::<Pkcs7>(ciphertext)
+        .map_err(|_| Error::Decrypt)?;
+
+    if synthetic_iv(key, &plaintext) != iv {
+        return Err(Error::Decrypt);
+    }
+
+    Ok(plaintext)
+}
+
+/// Encrypt `input_file` into [`encrypted_file`].
+///
+/// # Errors
+/// - any error from [`read_key`] or on file access
+pub fn encrypt_file(config: &Config, input_file: impl AsRef<Path>) -> Result<PathBuf> {
+    let key = read_key(config)?;
+
+    let data = fs::read(&input_file)?;
+    let encrypted_file = encrypted_file(input_file);
+
+    let current = fs::read_to_string(&encrypted_file).ok();
+    write_if_changed(&encrypted_file, current.as_deref(), &encrypt(&key, &data))?;
+
+    Ok(encrypted_file)
+}
+
+/// Decrypt [`encrypted_file`] into `input_file`.
+///
+/// # Errors
+/// - any error from [`read_key`] or [`decrypt`], or on file access
+pub fn decrypt_file(config: &Config, input_file: impl AsRef<Path>) -> Result<()> {
+    let key = read_key(config)?;
+
+    let data = decrypt(&key, &fs::read_to_string(encrypted_file(&input_file))?)?;
+    let data = String::from_utf8(data).map_err(|_| Error::Decrypt)?;
+
+    let current = fs::read_to_string(&input_file).ok();
+    write_if_changed(input_file.as_ref(), current.as_deref(), &data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: Key = [7; 32];
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let data = b"L68\nL30\nR48\n";
+
+        let encrypted = encrypt(&KEY, data);
+        assert_eq!(encrypted, encrypt(&KEY, data));
+        assert_eq!(decrypt(&KEY, &encrypted).unwrap(), data);
+    }
+
+    #[test]
+    fn test_decrypt_wrong_key() {
+        let encrypted = encrypt(&KEY, b"L68\nL30\nR48\n");
+
+        assert!(matches!(decrypt(&[8; 32], &encrypted), Err(Error::Decrypt)));
+    }
+
+    #[test]
+    fn test_encrypted_file() {
+        assert_eq!(
+            encrypted_file("../input.alice"),
+            PathBuf::from("../input.alice.enc")
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{Config, Error, Result, cargo_info, fetch, is_day_unlocked, write_if_changed};
+
+const ARTICLE_TAG: &str = "<article class=\"day-desc\">";
+const CODE_START_TAG: &str = "<pre><code>";
+const CODE_END_TAG: &str = "</code></pre>";
+
+/// Marker of a downloaded puzzle page in the examples dir, with the number of its parts.
+pub const EXAMPLES_MARKER: &str = "examples.fetched";
+
+fn decode(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// `(part, example)` for every `<pre><code>` block of a puzzle page.
+#[must_use]
+pub fn extract_examples(page: &str) -> Vec<(u32, String)> {
+    page.split(ARTICLE_TAG)
+        .skip(1)
+        .zip(1..)
+        .flat_map(|(article, part)| {
+            let article = article
+                .find("</article>")
+                .map_or(article, |end| &article[..end]);
+
+            article
+                .split(CODE_START_TAG)
+                .skip(1)
+                .filter_map(|block| block.find(CODE_END_TAG).map(|end| &block[..end]))
+                .map(move |block| (part, decode(block)))
+        })
+        .collect()
+}
+
+/// `(part, n)` of an `example-<part>-<n>` file.
+fn example_index(path: &Path) -> Option<(u32, u32)> {
+    let (part, n) = path
+        .file_name()?
+        .to_str()?
+        .strip_prefix("example-")?
+        .split_once('-')?;
+    Some((part.parse().ok()?, n.parse().ok()?))
+}
+
+fn example_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| example_index(path).is_some())
+            .collect::<Vec<_>>(),
+        Err(_) => vec![],
+    };
+    files.sort_by_key(|path| example_index(path));
+    files
+}
+
+/// # Errors
+/// See [`try_get_examples_with`].
+pub fn try_get_examples(year: i32, day: u32, dir: impl AsRef<Path>) -> Result<Vec<PathBuf>> {
+    try_get_examples_with(&Config::from_env(), year, day, dir)
+}
+
+/// Save the examples of the puzzle page in `dir` as `example-<part>-<n>`, with the
+/// [`EXAMPLES_MARKER`] of the page.
+///
+/// The saved examples are returned once the page had part 2, or for the last day of the
+/// event that has none; until then the page is downloaded again, so the examples of part
+/// 2 show up once it unlocks.
+///
+/// # Errors
+/// - [`Error::NotYetUnlocked`] when the day is locked
+/// - any session, transport or file error
+pub fn try_get_examples_with(
+    config: &Config,
+    year: i32,
+    day: u32,
+    dir: impl AsRef<Path>,
+) -> Result<Vec<PathBuf>> {
+    let dir = dir.as_ref();
+
+    let marker = dir.join(EXAMPLES_MARKER);
+    let parts = fs::read_to_string(&marker)
+        .ok()
+        .and_then(|parts| parts.trim().parse::<u32>().ok());
+    if parts.is_some_and(|parts| parts >= 2 || day == config.days(year)) {
+        return Ok(example_files(dir));
+    }
+
+    if !is_day_unlocked(config, year, day)? {
+        return Err(Error::NotYetUnlocked { year, day });
+    }
+
+    let url = config.url(&format!("/{year}/day/{day}"));
+
+    let page = fetch(config, year, day, &url, "text/html")?;
+
+    fs::create_dir_all(dir)?;
+
+    let (mut part_1, mut part_2) = (0, 0);
+    let mut files = vec![];
+    for (part, example) in extract_examples(&page) {
+        let counter = if part == 1 { &mut part_1 } else { &mut part_2 };
+        *counter += 1;
+
+        let file = dir.join(format!("example-{part}-{counter}"));
+        let current = fs::read_to_string(&file).ok();
+        write_if_changed(&file, current.as_deref(), &example)?;
+
+        files.push(file);
+    }
+
+    let parts = page.matches(ARTICLE_TAG).count();
+    let current = fs::read_to_string(&marker).ok();
+    write_if_changed(&marker, current.as_deref(), &format!("{parts}\n"))?;
+
+    Ok(files)
+}
+
+/// Save the examples next to the input file of the crate in the current directory.
+///
+/// # Errors
+/// - any error from [`cargo_info`] or [`try_get_examples_with`]
+pub fn try_get_examples_info_from_cargo(input_file: Option<String>) -> Result<Vec<PathBuf>> {
+    let info = cargo_info(input_file)?;
+
+    let dir = Path::new(&info.input_file)
+        .parent()
+        .map_or_else(|| PathBuf::from("."), Path::to_path_buf);
+
+    try_get_examples_with(&info.config, info.year, info.day, dir)
+}
+
+/// # Panics
+pub fn get_examples_info_from_cargo(input_file: Option<String>) {
+    match try_get_examples_info_from_cargo(input_file) {
+        Ok(_) | Err(Error::NotYetUnlocked { .. }) => {}
+        Err(err) => panic!("cannot get examples: {err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Secret Entrance ---</h2><p>For example:</p>
+<pre><code>L68
+L30
+R48
+</code></pre>
+<p>Result <code>3</code>.</p>
+<pre><code>a &lt;-&gt; b &amp; <em>c</em>
+</code></pre>
+</article>
+<p>Your puzzle answer was <code>1</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>R1000
+</code></pre>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract_examples() {
+        assert_eq!(
+            extract_examples(PAGE),
+            vec![
+                (1, "L68\nL30\nR48\n".to_string()),
+                (1, "a <-> b & c\n".to_string()),
+                (2, "R1000\n".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_example_files() {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-example-files", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for name in [
+            "example-1-10",
+            "example-2-1",
+            "example-1-2",
+            "example-1-1",
+            EXAMPLES_MARKER,
+        ] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+
+        let files = example_files(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            files,
+            ["example-1-1", "example-1-2", "example-1-10", "example-2-1"]
+                .map(|name| dir.join(name))
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use dirs::config_dir;
+
+use reqwest::{
+    StatusCode,
+    blocking::{Client as HttpClient, Response},
+    header::{self, HeaderMap, HeaderValue},
+    redirect::Policy,
+};
+
+use crate::{Config, Error, Result};
+
+/// Warn when other users can read the session file.
+#[cfg(unix)]
+fn check_permissions(config: &Config, session_file: &Path) {
+    use std::os::unix::fs::PermissionsExt;
+
+    if let Ok(metadata) = fs::metadata(session_file)
+        && metadata.permissions().mode() & 0o044 != 0
+    {
+        config.warn(format_args!(
+            "session file {} is readable by other users, run `chmod 600` on it",
+            session_file.display()
+        ));
+    }
+}
+
+#[cfg(not(unix))]
+fn check_permissions(_config: &Config, _session_file: &Path) {}
+
+fn read_session(config: &Config) -> Result<String> {
+    if let Some(session) = &config.session {
+        return Ok(session.expose().trim().to_string());
+    }
+
+    let session_file = match &config.session_file {
+        Some(session_file) => session_file.clone(),
+        None => config_dir()
+            .ok_or(Error::SessionMissing("cannot find config dir".to_string()))?
+            .join(config.session_file_name()),
+    };
+
+    let session_key = fs::read_to_string(&session_file).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => Error::SessionMissing(session_file.display().to_string()),
+        _ => Error::Io(err),
+    })?;
+
+    let session_key = session_key.trim();
+    if session_key.is_empty() {
+        return Err(Error::SessionMissing(session_file.display().to_string()));
+    }
+
+    check_permissions(config, &session_file);
+
+    Ok(session_key.to_string())
+}
+
+pub(crate) fn http_client(config: &Config) -> Result<HttpClient> {
+    let session_key = read_session(config)?;
+
+    let mut cookie_header = HeaderValue::from_str(&format!("session={session_key}"))
+        .map_err(|_| Error::SessionMissing("invalid session key".to_string()))?;
+    cookie_header.set_sensitive(true);
+    let user_agent_header = HeaderValue::from_str(&format!(
+        "{} {}",
+        env!("CARGO_PKG_REPOSITORY"),
+        env!("CARGO_PKG_VERSION")
+    ))
+    .unwrap();
+
+    let mut headers = HeaderMap::new();
+    headers.insert(header::COOKIE, cookie_header);
+    headers.insert(header::USER_AGENT, user_agent_header);
+
+    Ok(HttpClient::builder()
+        .default_headers(headers)
+        .redirect(Policy::none())
+        .build()?)
+}
+
+pub(crate) fn check_status(year: i32, day: u32, response: Response) -> Result<Response> {
+    match response.status() {
+        status if status.is_success() => Ok(response),
+        status if status.is_redirection() => Err(Error::SessionExpired),
+        StatusCode::BAD_REQUEST => Err(Error::SessionRejected(StatusCode::BAD_REQUEST)),
+        StatusCode::NOT_FOUND => Err(Error::NotYetUnlocked { year, day }),
+        status => Err(Error::HttpStatus(status)),
+    }
+}
+
+/// Check that the session is still logged in, without using the throttle.
+///
+/// # Errors
+/// - [`Error::SessionExpired`] when the site redirects or answers with the login page
+/// - [`Error::SessionMissing`] or [`Error::SessionRejected`] when the session is not usable
+/// - any transport error
+pub fn validate_session(config: &Config) -> Result<()> {
+    let response = http_client(config)?.get(config.url("/settings")).send()?;
+
+    match response.status() {
+        status if status.is_redirection() => Err(Error::SessionExpired),
+        status if status.is_success() => {
+            let page = response.text()?;
+            if page.contains("/auth/login") || page.contains("[Log In]") {
+                Err(Error::SessionExpired)
+            } else {
+                Ok(())
+            }
+        }
+        status => Err(Error::SessionRejected(status)),
+    }
+}
+
+/// Exclusive cross-process lock on `path`, released when the file is dropped.
+pub(crate) fn lock(path: &Path) -> Result<File> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)?;
+    file.lock()?;
+
+    Ok(file)
+}
+
+/// Cache dir of the tools, `adventofcode` in the user cache dir by default.
+pub(crate) fn cache_dir(config: &Config) -> Result<PathBuf> {
+    match &config.cache_dir {
+        Some(dir) => Ok(dir.clone()),
+        None => Ok(dirs::cache_dir()
+            .ok_or(Error::Metadata("cannot find cache dir"))?
+            .join("adventofcode")),
+    }
+}
+
+/// Lock on the last request to a URL, held from the throttle check until the request is
+/// recorded.
+struct Throttle(File);
+
+impl Throttle {
+    /// Wait for the throttle window of `url` to pass since its last request, sleeping
+    /// with [`Config::wait_throttled`] and failing otherwise; `None` without a window.
+    fn acquire(config: &Config, url: &str) -> Result<Option<Self>> {
+        if config.throttle.is_zero() {
+            return Ok(None);
+        }
+
+        let dir = cache_dir(config)?;
+        let name = url
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+
+        let mut file = lock(&dir.join("throttle").join(name))?;
+
+        let mut content = String::new();
+        file.read_to_string(&mut content)?;
+
+        if let Ok(last) = content.trim().parse::<u64>() {
+            let elapsed = now().saturating_sub(Duration::from_millis(last));
+            if elapsed < config.throttle {
+                let wait = config.throttle.saturating_sub(elapsed);
+                if !config.wait_throttled {
+                    return Err(Error::Throttled {
+                        url: url.to_string(),
+                        wait,
+                    });
+                }
+                thread::sleep(wait);
+            }
+        }
+
+        Ok(Some(Self(file)))
+    }
+
+    /// Record a request that reached the server.
+    fn record(mut self) -> Result<()> {
+        self.0.set_len(0)?;
+        self.0.rewind()?;
+        write!(self.0, "{}", now().as_millis())?;
+        Ok(())
+    }
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// GET `url` at most once per throttle window, retrying with exponential backoff
+/// on server errors; only the requests answered by the server count for the throttle.
+pub(crate) fn fetch(
+    config: &Config,
+    year: i32,
+    day: u32,
+    url: &str,
+    accept: &str,
+) -> Result<String> {
+    let client = http_client(config)?;
+
+    let mut throttle = Throttle::acquire(config, url)?;
+
+    let mut delay = config.retry_delay;
+    let mut attempt = 0;
+    loop {
+        let response = client.get(url).header(header::ACCEPT, accept).send()?;
+        // a locked day is gated by its unlock time, not by the throttle
+        if response.status() != StatusCode::NOT_FOUND
+            && let Some(throttle) = throttle.take()
+        {
+            throttle.record()?;
+        }
+        let result = check_status(year, day, response);
+
+        match result {
+            Err(Error::HttpStatus(status))
+                if status.is_server_error() && attempt < config.retries =>
+            {
+                attempt += 1;
+                thread::sleep(delay);
+                delay *= 2;
+            }
+            result => return Ok(result?.text()?),
+        }
+    }
+}
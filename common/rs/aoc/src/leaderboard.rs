@@ -0,0 +1,296 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::time::{Duration, SystemTime};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use reqwest::StatusCode;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Config, Error, Result, cache_dir, fetch, write_if_changed};
+
+/// Minimum time between two downloads of the same leaderboard, as requested by the site.
+pub const POLL_INTERVAL: Duration = Duration::from_mins(15);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+    #[serde(default)]
+    pub star_index: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    #[serde(default)]
+    pub last_star_ts: i64,
+    /// Stars by day and part, both as strings like in the site JSON.
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<String, BTreeMap<String, Star>>,
+}
+
+impl Member {
+    #[must_use]
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    fn star(&self, day: u32, part: u32) -> Option<DateTime<Utc>> {
+        let star = self
+            .completion_day_level
+            .get(&day.to_string())?
+            .get(&part.to_string())?;
+        DateTime::from_timestamp(star.get_star_ts, 0)
+    }
+}
+
+/// Private leaderboard as served by `/{year}/leaderboard/private/view/{id}.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DayReport {
+    pub day: u32,
+    pub part_1: Option<DateTime<Utc>>,
+    pub part_2: Option<DateTime<Utc>>,
+    /// Seconds between the part 1 and the part 2 star.
+    pub part_2_delta: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MemberReport {
+    pub rank: usize,
+    pub id: u64,
+    pub name: String,
+    pub local_score: u64,
+    pub stars: u32,
+    pub days: Vec<DayReport>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub event: String,
+    pub members: Vec<MemberReport>,
+}
+
+impl Leaderboard {
+    /// # Errors
+    /// - [`Error::Json`] when `json` is not a leaderboard
+    pub fn parse(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Members ranked by local score, then stars, then time of the last star.
+    #[must_use]
+    pub fn report(&self) -> Report {
+        let mut members = self.members.values().collect::<Vec<_>>();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.last_star_ts.cmp(&b.last_star_ts))
+                .then(a.id.cmp(&b.id))
+        });
+
+        let members = members
+            .into_iter()
+            .zip(1..)
+            .map(|(member, rank)| {
+                let mut days = member
+                    .completion_day_level
+                    .keys()
+                    .filter_map(|day| day.parse().ok())
+                    .map(|day| {
+                        let (part_1, part_2) = (member.star(day, 1), member.star(day, 2));
+                        DayReport {
+                            day,
+                            part_1,
+                            part_2,
+                            part_2_delta: part_1
+                                .zip(part_2)
+                                .map(|(part_1, part_2)| (part_2 - part_1).num_seconds()),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                days.sort_by_key(|day| day.day);
+
+                MemberReport {
+                    rank,
+                    id: member.id,
+                    name: member.display_name(),
+                    local_score: member.local_score,
+                    stars: member.stars,
+                    days,
+                }
+            })
+            .collect();
+
+        Report {
+            event: self.event.clone(),
+            members,
+        }
+    }
+}
+
+impl Report {
+    /// # Errors
+    /// - [`Error::Json`] when the report cannot be serialized
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+fn format_star(star: Option<DateTime<Utc>>) -> String {
+    star.map_or_else(
+        || "-".to_string(),
+        |star| star.to_rfc3339_opts(SecondsFormat::Secs, true),
+    )
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Advent of Code {} private leaderboard", self.event)?;
+        for member in &self.members {
+            writeln!(
+                f,
+                "{:>3}) {:<30} {:>5} points {:>3} stars",
+                member.rank, member.name, member.local_score, member.stars
+            )?;
+            for day in &member.days {
+                write!(
+                    f,
+                    "       day {:>2}  {:<20}  {:<20}",
+                    day.day,
+                    format_star(day.part_1),
+                    format_star(day.part_2)
+                )?;
+                if let Some(delta) = day.part_2_delta {
+                    write!(
+                        f,
+                        "  +{:02}:{:02}:{:02}",
+                        delta / 3600,
+                        delta / 60 % 60,
+                        delta % 60
+                    )?;
+                }
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// # Errors
+/// See [`try_get_leaderboard_with`].
+pub fn try_get_leaderboard(year: i32, id: u64) -> Result<Leaderboard> {
+    try_get_leaderboard_with(&Config::from_env(), year, id)
+}
+
+/// Private leaderboard `id` of `year`, downloaded at most once every [`POLL_INTERVAL`]
+/// and served from the cache dir in between.
+///
+/// # Errors
+/// - [`Error::HttpStatus`] when the leaderboard does not exist
+/// - [`Error::Json`] when the response is not a leaderboard
+/// - any session, transport or file error
+pub fn try_get_leaderboard_with(config: &Config, year: i32, id: u64) -> Result<Leaderboard> {
+    let cache = cache_dir(config)?
+        .join("leaderboard")
+        .join(format!("{year}-{id}.json"));
+
+    let fresh = fs::metadata(&cache)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < POLL_INTERVAL);
+    if fresh && let Ok(leaderboard) = Leaderboard::parse(&fs::read_to_string(&cache)?) {
+        return Ok(leaderboard);
+    }
+
+    let url = config.url(&format!("/{year}/leaderboard/private/view/{id}.json"));
+
+    let json = match fetch(config, year, 0, &url, "application/json") {
+        Err(Error::NotYetUnlocked { .. }) => Err(Error::HttpStatus(StatusCode::NOT_FOUND)),
+        result => result,
+    }?;
+    let leaderboard = Leaderboard::parse(&json)?;
+
+    if let Some(parent) = cache.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    write_if_changed(&cache, None, &json)?;
+
+    Ok(leaderboard)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEADERBOARD: &str = r#"{
+  "event": "2025",
+  "owner_id": 1,
+  "members": {
+    "1": {
+      "id": 1, "name": "alice", "stars": 3, "local_score": 5, "last_star_ts": 1764566000,
+      "completion_day_level": {
+        "1": {
+          "1": { "get_star_ts": 1764565380, "star_index": 10 },
+          "2": { "get_star_ts": 1764565510, "star_index": 11 }
+        },
+        "2": { "1": { "get_star_ts": 1764566000, "star_index": 20 } }
+      }
+    },
+    "2": {
+      "id": 2, "name": null, "stars": 3, "local_score": 5, "last_star_ts": 1764565900,
+      "completion_day_level": {
+        "1": {
+          "1": { "get_star_ts": 1764565300, "star_index": 9 },
+          "2": { "get_star_ts": 1764565900, "star_index": 12 }
+        }
+      }
+    },
+    "3": {
+      "id": 3, "name": "carol", "stars": 0, "local_score": 0, "last_star_ts": 0,
+      "completion_day_level": {}
+    }
+  }
+}"#;
+
+    #[test]
+    fn test_report() {
+        let report = Leaderboard::parse(LEADERBOARD).unwrap().report();
+
+        assert_eq!(
+            report
+                .members
+                .iter()
+                .map(|member| (member.rank, member.name.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(1, "(anonymous user #2)"), (2, "alice"), (3, "carol")]
+        );
+
+        let alice = &report.members[1];
+        assert_eq!(alice.days.len(), 2);
+        assert_eq!(alice.days[0].part_2_delta, Some(130));
+        assert_eq!(alice.days[1].part_2, None);
+        assert_eq!(alice.days[1].part_2_delta, None);
+
+        let text = report.to_string();
+        assert!(text.contains("2025-12-01T05:03:00Z"));
+        assert!(text.contains("+00:02:10"));
+
+        assert!(report.to_json().unwrap().contains("\"part_2_delta\": 130"));
+    }
+}
@@ -1,131 +1,740 @@
+use std::env;
+use std::fmt;
 use std::fs;
-use std::path::PathBuf;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::Duration;
 
 use toml::{Table, Value};
 
-use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
 
-use dirs::config_dir;
+use reqwest::StatusCode;
 
-use reqwest::{
-    blocking::{Client as HttpClient, Response},
-    header::{self, HeaderMap, HeaderValue},
-    redirect::Policy,
+mod answers;
+pub use answers::{ANSWERS_FILE, AnswerCheck, Answers, KnownAnswers, answers_file};
+
+mod crypt;
+pub use crypt::{Key, decrypt, decrypt_file, encrypt, encrypt_file, encrypted_file, read_key};
+
+mod http;
+pub use http::validate_session;
+pub(crate) use http::{cache_dir, check_status, fetch, http_client, lock};
+
+mod examples;
+pub use examples::{
+    EXAMPLES_MARKER, extract_examples, get_examples_info_from_cargo, try_get_examples,
+    try_get_examples_info_from_cargo, try_get_examples_with,
+};
+
+mod leaderboard;
+pub use leaderboard::{
+    DayReport, Leaderboard, Member, MemberReport, POLL_INTERVAL, Report, Star, try_get_leaderboard,
+    try_get_leaderboard_with,
+};
+
+mod manifest;
+pub(crate) use manifest::record_input;
+pub use manifest::{
+    InputCheck, InputEntry, MANIFEST_FILE, Manifest, check_input, manifest_file, sha256,
+};
+
+mod scaffold;
+pub use scaffold::{day_dir, scaffold_day, workspace_root, workspace_year};
+
+mod submit;
+pub use submit::{
+    Attempt, History, Verdict, history_file, parse_verdict, submit_answer, submit_answer_for_input,
+    submit_answer_with,
 };
 
-fn is_day_unlocked(year: i32, day: u32) -> bool {
-    let timezone = FixedOffset::east_opt(-5 * 3600).unwrap();
-    let now = timezone.from_utc_datetime(&Utc::now().naive_utc());
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Session missing: {0}")]
+    SessionMissing(String),
+
+    #[error("Session rejected (status {0})")]
+    SessionRejected(StatusCode),
+
+    #[error("Session expired, log in again and update the session cookie")]
+    SessionExpired,
+
+    #[error("Day {day} of {year} not yet unlocked")]
+    NotYetUnlocked { year: i32, day: u32 },
+
+    #[error(
+        "Input of day {day} of {year} missing and fetching disabled, set AOC_FETCH=1, \
+         enable the `fetch` feature or run `cargo aoc fetch`"
+    )]
+    FetchDisabled { year: i32, day: u32 },
+
+    #[error("Day {day} of {year} does not exist")]
+    InvalidDay { year: i32, day: u32 },
+
+    #[error("HTTP status {0}")]
+    HttpStatus(StatusCode),
+
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("Invalid metadata: {0}")]
+    Metadata(&'static str),
+
+    #[error("Invalid part {0}")]
+    InvalidPart(u32),
+
+    #[error("Answer {answer} already known to be {verdict}")]
+    RuledOut { answer: String, verdict: Verdict },
+
+    #[error("Unexpected response page")]
+    UnexpectedResponse,
+
+    #[error("Invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Key missing: {0}")]
+    KeyMissing(String),
+
+    #[error("Cannot decrypt input: wrong key or corrupted file")]
+    Decrypt,
+
+    #[error("Request to {url} throttled, retry in {}s", wait.as_secs() + 1)]
+    Throttled { url: String, wait: Duration },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Overrides the Advent of Code endpoint, e.g. `http://127.0.0.1:8080`.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+/// Session cookie value, used instead of the session file.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Overrides the session file path.
+pub const SESSION_FILE_ENV: &str = "AOC_SESSION_FILE";
+
+/// Overrides the directory of the answer histories.
+pub const HISTORY_DIR_ENV: &str = "AOC_HISTORY_DIR";
+
+/// Overrides the key file used to encrypt and decrypt inputs.
+pub const KEY_FILE_ENV: &str = "AOC_KEY_FILE";
+
+/// Overrides the directory of the cached responses and request timestamps.
+pub const CACHE_DIR_ENV: &str = "AOC_CACHE_DIR";
+
+/// Overrides the minimum number of seconds between two requests to the same URL.
+pub const THROTTLE_ENV: &str = "AOC_THROTTLE_SECS";
+
+/// Overrides the number of retries on server errors.
+pub const RETRIES_ENV: &str = "AOC_RETRIES";
+
+/// Overrides the UTC offset, in hours, of the puzzle unlock time.
+pub const UTC_OFFSET_ENV: &str = "AOC_UTC_OFFSET";
+
+/// Overrides the number of days of the event.
+pub const DAYS_ENV: &str = "AOC_DAYS";
+
+/// Allows (`1`) or forbids (`0`) downloads; build scripts only download when set
+/// to `1` or when the `fetch` feature of the day crate is enabled.
+pub const FETCH_ENV: &str = "AOC_FETCH";
+
+/// Selects a named session profile, e.g. `alice` reads `adventofcode.alice.session`
+/// and stores the inputs as `input.alice`.
+pub const PROFILE_ENV: &str = "AOC_PROFILE";
+
+/// Set by [`build_input`] to the absolute path of the input of the active profile.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Set by [`build_input`] to the known answer of part 1 of the input, when the
+/// workspace [`ANSWERS_FILE`] has one.
+pub const ANSWER_1_ENV: &str = "AOC_ANSWER_1";
+
+/// Set by [`build_input`] to the known answer of part 2 of the input, when the
+/// workspace [`ANSWERS_FILE`] has one.
+pub const ANSWER_2_ENV: &str = "AOC_ANSWER_2";
+
+/// Set by [`build_workspace_year`] to the event of the days in `days/`.
+pub const WORKSPACE_YEAR_ENV: &str = "AOC_WORKSPACE_YEAR";
 
-    let local_datetime = NaiveDate::from_ymd_opt(year, 12, day)
-        .unwrap()
-        .and_hms_opt(0, 0, 0)
-        .unwrap();
+/// Session cookie value, never shown by [`fmt::Debug`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct Session(String);
 
-    let unlock_datetime = timezone
-        .from_local_datetime(&local_datetime)
+impl Session {
+    #[must_use]
+    pub fn new(session: impl Into<String>) -> Self {
+        Self(session.into())
+    }
+
+    pub(crate) fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Session {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Session(..)")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub base_url: String,
+    pub session: Option<Session>,
+    pub session_file: Option<PathBuf>,
+    pub history_dir: Option<PathBuf>,
+    pub profile: Option<String>,
+    pub key_file: Option<PathBuf>,
+    pub cache_dir: Option<PathBuf>,
+    pub throttle: Duration,
+    /// Sleep out the rest of the [`Config::throttle`] window instead of failing with
+    /// [`Error::Throttled`]; off in the `cargo aoc` commands.
+    pub wait_throttled: bool,
+    pub retries: u32,
+    pub retry_delay: Duration,
+    pub utc_offset_hours: i32,
+    pub days: Option<u32>,
+    pub fetch: bool,
+    /// Report the warnings to cargo instead of stderr, on in [`build_input`].
+    pub cargo_warnings: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: None,
+            session_file: None,
+            history_dir: None,
+            profile: None,
+            key_file: None,
+            cache_dir: None,
+            throttle: Duration::from_mins(1),
+            wait_throttled: true,
+            retries: 3,
+            retry_delay: Duration::from_secs(1),
+            utc_offset_hours: -5,
+            days: None,
+            fetch: true,
+            cargo_warnings: false,
+        }
+    }
+}
+
+impl Config {
+    /// Default configuration overridden by [`BASE_URL_ENV`], [`SESSION_ENV`], [`SESSION_FILE_ENV`],
+    /// [`HISTORY_DIR_ENV`], [`PROFILE_ENV`], [`KEY_FILE_ENV`], [`CACHE_DIR_ENV`],
+    /// [`THROTTLE_ENV`], [`RETRIES_ENV`], [`UTC_OFFSET_ENV`], [`DAYS_ENV`] and [`FETCH_ENV`].
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::default().with_env()
+    }
+
+    #[must_use]
+    fn with_env(mut self) -> Self {
+        if let Ok(base_url) = env::var(BASE_URL_ENV) {
+            self.base_url = base_url;
+        }
+        if let Ok(session) = env::var(SESSION_ENV)
+            && !session.trim().is_empty()
+        {
+            self.session = Some(Session::new(session));
+        }
+        if let Ok(session_file) = env::var(SESSION_FILE_ENV) {
+            self.session_file = Some(PathBuf::from(session_file));
+        }
+        if let Ok(history_dir) = env::var(HISTORY_DIR_ENV) {
+            self.history_dir = Some(PathBuf::from(history_dir));
+        }
+        if let Ok(profile) = env::var(PROFILE_ENV) {
+            self.profile = Some(profile).filter(|profile| !profile.is_empty());
+        }
+        if let Ok(key_file) = env::var(KEY_FILE_ENV) {
+            self.key_file = Some(PathBuf::from(key_file));
+        }
+        if let Ok(cache_dir) = env::var(CACHE_DIR_ENV) {
+            self.cache_dir = Some(PathBuf::from(cache_dir));
+        }
+        if let Some(throttle) = env::var(THROTTLE_ENV).ok().and_then(|v| v.parse().ok()) {
+            self.throttle = Duration::from_secs(throttle);
+        }
+        if let Some(retries) = env::var(RETRIES_ENV).ok().and_then(|v| v.parse().ok()) {
+            self.retries = retries;
+        }
+        if let Some(utc_offset) = env::var(UTC_OFFSET_ENV).ok().and_then(|v| v.parse().ok()) {
+            self.utc_offset_hours = utc_offset;
+        }
+        if let Some(days) = env::var(DAYS_ENV).ok().and_then(|v| v.parse().ok()) {
+            self.days = Some(days);
+        }
+        if let Ok(fetch) = env::var(FETCH_ENV) {
+            self.fetch = fetch == "1";
+        }
+        self
+    }
+
+    /// Number of days of the event: 25 until 2024, 12 from 2025 unless configured.
+    #[must_use]
+    pub fn days(&self, year: i32) -> u32 {
+        self.days.unwrap_or(if year < 2025 { 25 } else { 12 })
+    }
+
+    /// Input file of the active profile: `input_file` itself without profile,
+    /// `input_file.<profile>` otherwise.
+    #[must_use]
+    pub fn input_file(&self, input_file: &str) -> String {
+        match &self.profile {
+            Some(profile) => format!("{input_file}.{profile}"),
+            None => input_file.to_string(),
+        }
+    }
+
+    /// Print a warning on stderr, or as a `cargo::warning` from a build script, whose
+    /// stderr cargo only shows when it fails.
+    pub(crate) fn warn(&self, message: impl fmt::Display) {
+        if self.cargo_warnings {
+            println!("cargo::warning={message}");
+        } else {
+            eprintln!("warning: {message}");
+        }
+    }
+
+    fn session_file_name(&self) -> String {
+        match &self.profile {
+            Some(profile) => format!("adventofcode.{profile}.session"),
+            None => "adventofcode.session".to_string(),
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url.trim_end_matches('/'))
+    }
+}
+
+/// Unlock time of a puzzle: midnight of December `day` in the event timezone.
+///
+/// # Errors
+/// - [`Error::InvalidDay`] when the event has no such day
+/// - [`Error::Metadata`] when the UTC offset is invalid
+pub fn unlock_time(config: &Config, year: i32, day: u32) -> Result<DateTime<FixedOffset>> {
+    if day == 0 || day > config.days(year) {
+        return Err(Error::InvalidDay { year, day });
+    }
+
+    FixedOffset::east_opt(config.utc_offset_hours * 3600)
+        .ok_or(Error::Metadata("invalid utc offset"))?
+        .with_ymd_and_hms(year, 12, day, 0, 0, 0)
         .single()
-        .unwrap();
+        .ok_or(Error::InvalidDay { year, day })
+}
 
-    now.signed_duration_since(unlock_datetime)
-        .num_milliseconds()
-        >= 0
+/// # Errors
+/// See [`unlock_time`].
+pub fn is_day_unlocked(config: &Config, year: i32, day: u32) -> Result<bool> {
+    Ok(Utc::now() >= unlock_time(config, year, day)?)
 }
 
-/// # Panics
-pub fn get_input(year: i32, day: u32, input_file: &str) {
+/// Sleep until the puzzle unlocks, calling `tick` with the remaining time about
+/// once per second.
+///
+/// # Errors
+/// See [`unlock_time`].
+pub fn wait_for_unlock(
+    config: &Config,
+    year: i32,
+    day: u32,
+    mut tick: impl FnMut(Duration),
+) -> Result<()> {
+    let unlock_time = unlock_time(config, year, day)?;
+    while let Ok(remaining) = (unlock_time.with_timezone(&Utc) - Utc::now()).to_std() {
+        if remaining.is_zero() {
+            break;
+        }
+        tick(remaining);
+        thread::sleep(remaining.min(Duration::from_secs(1)));
+    }
+    Ok(())
+}
+
+/// Path of the marker written instead of the input while the day is locked.
+#[must_use]
+pub fn locked_file(input_file: impl AsRef<Path>) -> PathBuf {
+    let mut file = input_file.as_ref().as_os_str().to_owned();
+    file.push(".locked");
+    PathBuf::from(file)
+}
+
+/// Write through a temporary file renamed over `input`, so readers never see a
+/// truncated file.
+pub(crate) fn write_if_changed(input: &Path, current: Option<&str>, data: &str) -> Result<()> {
+    if current.is_none_or(|content| content != data) {
+        let mut tmp = input.as_os_str().to_owned();
+        tmp.push(format!(".tmp.{}", process::id()));
+
+        fs::write(&tmp, data)?;
+        fs::rename(&tmp, input).inspect_err(|_| {
+            fs::remove_file(&tmp).ok();
+        })?;
+    }
+    Ok(())
+}
+
+/// # Errors
+/// See [`try_get_input_with`].
+pub fn try_get_input(year: i32, day: u32, input_file: &str) -> Result<()> {
+    try_get_input_with(&Config::from_env(), year, day, input_file)
+}
+
+/// Get the input if it is missing, from the encrypted input or from the site, and keep
+/// its [`MANIFEST_FILE`] entry: downloads and first decryptions are recorded, a present
+/// input that does not match its entry is reported on stderr.
+///
+/// # Errors
+/// - [`Error::Decrypt`] when the encrypted input cannot be decrypted with the available key
+/// - [`Error::FetchDisabled`] when the input is missing and [`Config::fetch`] is off
+/// - [`Error::InvalidDay`] when the event has no such day
+/// - [`Error::NotYetUnlocked`] when the day is locked, after writing the [`locked_file`]
+///   marker instead of the input
+/// - [`Error::SessionMissing`], [`Error::SessionExpired`] or [`Error::SessionRejected`] when
+///   the session is not usable
+/// - [`Error::Throttled`] when the same input was requested less than
+///   [`Config::throttle`] ago and [`Config::wait_throttled`] is off
+/// - [`Error::Http`], [`Error::HttpStatus`] or [`Error::Io`] on transport or file failures,
+///   server errors are retried [`Config::retries`] times
+/// - [`Error::Metadata`] when the manifest is invalid
+pub fn try_get_input_with(config: &Config, year: i32, day: u32, input_file: &str) -> Result<()> {
     let input = PathBuf::from(input_file);
 
-    let input_data = fs::read_to_string(&input);
+    let input_data = fs::read_to_string(&input).ok();
 
-    let input_is_missing = input_data.as_ref().map(String::is_empty).unwrap_or(true);
+    let input_is_missing = input_data.as_deref().is_none_or(str::is_empty);
     if !input_is_missing {
-        return;
+        return check_manifest(config, year, day, &input, false);
     }
 
-    if !is_day_unlocked(year, day) {
-        let data = "";
-        let must_write = input_data.map(|content| content != data).unwrap_or(true);
-        if must_write {
-            fs::write(&input, data).expect("cannot write (default) input file");
+    if encrypted_file(&input).exists() {
+        match decrypt_file(config, &input) {
+            Ok(()) => return check_manifest(config, year, day, &input, true),
+            Err(Error::KeyMissing(_)) => {}
+            Err(err) => return Err(err),
         }
-        return;
     }
 
-    let session_key = fs::read_to_string(
-        config_dir()
-            .expect("cannot find config dir")
-            .join("adventofcode.session"),
-    )
-    .expect("cannot read session key");
+    if !config.fetch {
+        return Err(Error::FetchDisabled { year, day });
+    }
 
-    let cookie_header = HeaderValue::from_str(&format!("session={}", session_key.trim())).unwrap();
-    let content_type_header = HeaderValue::from_str("text/plain").unwrap();
-    let user_agent_header = HeaderValue::from_str(&format!(
-        "{} {}",
-        env!("CARGO_PKG_REPOSITORY"),
-        env!("CARGO_PKG_VERSION")
-    ))
-    .unwrap();
+    let result = fetch_input(config, year, day, &input);
 
-    let url = format!("https://adventofcode.com/{year}/day/{day}/input");
+    let locked = locked_file(&input);
+    match result {
+        Err(Error::NotYetUnlocked { .. }) => {
+            let current = fs::read_to_string(&locked).ok();
+            let unlock_time = unlock_time(config, year, day)?;
+            write_if_changed(
+                &locked,
+                current.as_deref(),
+                &format!(
+                    "day {day} of {year} unlocks at {}\n",
+                    unlock_time.to_rfc3339()
+                ),
+            )?;
+        }
+        Ok(()) if locked.exists() => fs::remove_file(&locked)?,
+        _ => {}
+    }
 
-    let mut headers = HeaderMap::new();
-    headers.insert(header::COOKIE, cookie_header);
-    headers.insert(header::CONTENT_TYPE, content_type_header);
-    headers.insert(header::USER_AGENT, user_agent_header);
+    result
+}
+
+fn fetch_input(config: &Config, year: i32, day: u32, input: &Path) -> Result<()> {
+    if !is_day_unlocked(config, year, day)? {
+        return Err(Error::NotYetUnlocked { year, day });
+    }
 
-    let data = HttpClient::builder()
-        .default_headers(headers)
-        .redirect(Policy::none())
-        .build()
-        .unwrap()
-        .get(url)
-        .send()
-        .and_then(Response::error_for_status)
-        .and_then(Response::text)
-        .unwrap();
+    // another process may have fetched the input while waiting for the lock
+    let mut lock_file = input.as_os_str().to_owned();
+    lock_file.push(".lock");
+    let _lock = lock(Path::new(&lock_file))?;
 
-    let must_write = input_data.map(|content| content != data).unwrap_or(true);
-    if must_write {
-        fs::write(&input, &data).expect("cannot write (default) input file");
+    let input_data = fs::read_to_string(input).ok();
+    if input_data
+        .as_deref()
+        .is_some_and(|content| !content.is_empty())
+    {
+        return Ok(());
     }
+
+    let url = config.url(&format!("/{year}/day/{day}/input"));
+
+    let data = fetch(config, year, day, &url, "text/plain")?;
+
+    write_if_changed(input, input_data.as_deref(), &data)?;
+
+    if let check @ InputCheck::Mismatch { .. } = check_input(input)? {
+        config.warn(format_args!(
+            "downloaded {} {check}, updating it",
+            input.display()
+        ));
+    }
+    record_input(config, year, day, input, Some(Utc::now()))?;
+
+    Ok(())
+}
+
+/// Warn when the input does not match its manifest entry, or record it when
+/// `record` is set and the manifest has no entry yet.
+fn check_manifest(config: &Config, year: i32, day: u32, input: &Path, record: bool) -> Result<()> {
+    match check_input(input)? {
+        InputCheck::Unrecorded if record => {
+            record_input(config, year, day, input, None)?;
+        }
+        check @ InputCheck::Mismatch { .. } => {
+            config.warn(format_args!("{} {check}", input.display()));
+        }
+        _ => {}
+    }
+    Ok(())
 }
 
 /// # Panics
-pub fn get_input_info_from_cargo(input_file: Option<String>) {
-    let config = fs::read_to_string("Cargo.toml")
-        .expect("cannot find Cargo.toml")
+pub fn get_input(year: i32, day: u32, input_file: &str) {
+    match try_get_input(year, day, input_file) {
+        Ok(()) | Err(Error::NotYetUnlocked { .. }) => {}
+        Err(err) => panic!("cannot get input: {err}"),
+    }
+}
+
+/// `package.metadata.aoc` of the crate in the current directory.
+#[derive(Debug, Clone)]
+pub struct CargoInfo {
+    pub config: Config,
+    pub year: i32,
+    pub day: u32,
+    pub input_file: String,
+}
+
+/// # Errors
+/// - [`Error::Io`] when `Cargo.toml` cannot be read
+/// - [`Error::Metadata`] when `package.metadata.aoc` is missing or invalid
+pub fn cargo_info(input_file: Option<String>) -> Result<CargoInfo> {
+    let config = fs::read_to_string("Cargo.toml")?
         .parse::<Table>()
-        .expect("invalid Cargo.toml");
+        .map_err(|_| Error::Metadata("invalid Cargo.toml"))?;
 
     let data = config
         .get("package")
         .and_then(|value| value.get("metadata"))
         .and_then(|value| value.get("aoc"))
-        .expect("cannot find package.metadata.aoc");
+        .ok_or(Error::Metadata("cannot find package.metadata.aoc"))?;
 
     let year = get_int(
         data.get("year")
-            .expect("cannot find package.metadata.aoc.year"),
+            .ok_or(Error::Metadata("cannot find package.metadata.aoc.year"))?,
     )
-    .expect("invalid year")
+    .ok_or(Error::Metadata("invalid year"))?
     .try_into()
-    .expect("invalid year value");
+    .map_err(|_| Error::Metadata("invalid year value"))?;
     let day = get_int(
         data.get("day")
-            .expect("cannot find package.metadata.aoc.day"),
+            .ok_or(Error::Metadata("cannot find package.metadata.aoc.day"))?,
     )
-    .expect("invalid day")
+    .ok_or(Error::Metadata("invalid day"))?
     .try_into()
-    .expect("invalid day value");
+    .map_err(|_| Error::Metadata("invalid day value"))?;
     let input_file = data
         .get("input_file")
         .and_then(get_string)
         .or(input_file)
-        .expect("invalid input file");
+        .ok_or(Error::Metadata("invalid input file"))?;
 
-    get_input(year, day, &input_file);
+    let mut config = Config::default();
+    if let Some(base_url) = data.get("base_url").and_then(get_string) {
+        config.base_url = base_url;
+    }
+    if let Some(session_file) = data.get("session_file").and_then(get_string) {
+        config.session_file = Some(PathBuf::from(session_file));
+    }
+    if let Some(history_dir) = data.get("history_dir").and_then(get_string) {
+        config.history_dir = Some(PathBuf::from(history_dir));
+    }
+    if let Some(profile) = data.get("profile").and_then(get_string) {
+        config.profile = Some(profile);
+    }
+    if let Some(throttle) = data.get("throttle_secs").and_then(get_int) {
+        config.throttle = Duration::from_secs(
+            throttle
+                .try_into()
+                .map_err(|_| Error::Metadata("invalid throttle"))?,
+        );
+    }
+    if let Some(utc_offset) = data.get("utc_offset").and_then(get_int) {
+        config.utc_offset_hours = utc_offset
+            .try_into()
+            .map_err(|_| Error::Metadata("invalid utc offset"))?;
+    }
+    if let Some(days) = data.get("days").and_then(get_int) {
+        config.days = Some(
+            days.try_into()
+                .map_err(|_| Error::Metadata("invalid days"))?,
+        );
+    }
+    if let Some(retries) = data.get("retries").and_then(get_int) {
+        config.retries = retries
+            .try_into()
+            .map_err(|_| Error::Metadata("invalid retries"))?;
+    }
+
+    let config = config.with_env();
+    let input_file = config.input_file(&input_file);
+
+    Ok(CargoInfo {
+        config,
+        year,
+        day,
+        input_file,
+    })
+}
+
+/// # Errors
+/// - any error from [`cargo_info`] or [`try_get_input_with`]
+pub fn try_get_input_info_from_cargo(input_file: Option<String>) -> Result<()> {
+    let info = cargo_info(input_file)?;
+
+    try_get_input_with(&info.config, info.year, info.day, &info.input_file)
+}
+
+/// # Panics
+pub fn get_input_info_from_cargo(input_file: Option<String>) {
+    match try_get_input_info_from_cargo(input_file) {
+        Ok(()) | Err(Error::NotYetUnlocked { .. }) => {}
+        Err(err) => panic!("cannot get input: {err}"),
+    }
+}
+
+fn empty_input() -> PathBuf {
+    let path = PathBuf::from(env::var("OUT_DIR").expect("cannot find OUT_DIR")).join("input");
+    fs::write(&path, "").expect("cannot write empty input");
+    path
+}
+
+/// Get the input of the crate in the current directory from a build script,
+/// exposing its path for the active profile as [`INPUT_ENV`].
+///
+/// The input is downloaded only with [`FETCH_ENV`] set to `1` or with the `fetch`
+/// feature of the crate; otherwise a missing input falls back to the first part 1
+/// example, or to an empty input, and so does the input of a locked day, quietly.
+///
+/// An input that does not match its [`MANIFEST_FILE`] entry builds with a warning,
+/// so every binary, test and bench of the crate reports it. The script reruns when the
+/// input, its encrypted input or its manifest changes, or their directory while one is
+/// missing.
+///
+/// The known answers of the input in the workspace [`ANSWERS_FILE`] are exposed as
+/// [`ANSWER_1_ENV`] and [`ANSWER_2_ENV`], for the benches to check.
+///
+/// # Panics
+/// When the input cannot be got, other than missing offline or locked.
+pub fn build_input(input_file: &str) {
+    println!("cargo::rerun-if-env-changed={PROFILE_ENV}");
+    println!("cargo::rerun-if-env-changed={FETCH_ENV}");
+
+    let mut info = cargo_info(Some(input_file.to_string()))
+        .unwrap_or_else(|err| panic!("cannot get input: {err}"));
+    info.config.fetch = env::var(FETCH_ENV).is_ok_and(|fetch| fetch == "1")
+        || env::var_os("CARGO_FEATURE_FETCH").is_some();
+    info.config.cargo_warnings = true;
+
+    let current_dir = env::current_dir().expect("cannot get current dir");
+
+    let path = match try_get_input_with(&info.config, info.year, info.day, &info.input_file) {
+        Ok(()) => {
+            known_answers(&info);
+            current_dir.join(&info.input_file)
+        }
+        // no warning: cargo replays them on every build, for every day of an offline
+        // workspace, until the script reruns
+        Err(Error::FetchDisabled { .. }) => {
+            let example = Path::new(&info.input_file).with_file_name("example-1-1");
+            rerun_if_changed(&example);
+            if example.exists() {
+                current_dir.join(example)
+            } else {
+                empty_input()
+            }
+        }
+        Err(Error::NotYetUnlocked { .. }) => empty_input(),
+        Err(err) => panic!("cannot get input: {err}"),
+    };
+
+    rerun_if_changed(Path::new(&info.input_file));
+    rerun_if_changed(&encrypted_file(&info.input_file));
+    rerun_if_changed(&manifest_file(&info.input_file));
+    println!("cargo::rustc-env={INPUT_ENV}={}", path.display());
+}
+
+/// Rerun the build script when `path` changes, or when its directory does while it is
+/// missing: cargo reruns it on every build for a missing path.
+fn rerun_if_changed(path: &Path) {
+    let path = if path.exists() {
+        path
+    } else {
+        path.parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."))
+    };
+    println!("cargo::rerun-if-changed={}", path.display());
+}
+
+/// Build script helper exposing the [`workspace_year`] as [`WORKSPACE_YEAR_ENV`], for
+/// the crates over the days of the workspace event.
+///
+/// # Panics
+/// When the workspace manifest has no valid year.
+pub fn build_workspace_year() {
+    let year = workspace_root().and_then(|root| {
+        println!(
+            "cargo::rerun-if-changed={}",
+            root.join("Cargo.toml").display()
+        );
+        workspace_year(root)
+    });
+    let year = year.unwrap_or_else(|err| panic!("cannot get workspace year: {err}"));
+    println!("cargo::rustc-env={WORKSPACE_YEAR_ENV}={year}");
+}
+
+fn known_answers(info: &CargoInfo) {
+    let answers = workspace_root().and_then(|root| {
+        let path = answers_file(root);
+        // not its directory while missing, the workspace root holds the target dir
+        if path.exists() {
+            println!("cargo::rerun-if-changed={}", path.display());
+        }
+        Answers::load(path)
+    });
+    let known = answers.and_then(|answers| {
+        let sha256 = sha256(&fs::read(&info.input_file)?);
+        Ok(answers.get(info.year, info.day, &sha256).cloned())
+    });
+
+    match known {
+        Ok(Some(known)) => {
+            for (env, answer) in [(ANSWER_1_ENV, known.part_1), (ANSWER_2_ENV, known.part_2)] {
+                if let Some(answer) = answer {
+                    println!("cargo::rustc-env={env}={answer}");
+                }
+            }
+        }
+        Ok(None) => {}
+        Err(err) => println!("cargo::warning=cannot read the known answers: {err}"),
+    }
 }
 
 fn get_int(value: &Value) -> Option<i64> {
@@ -141,3 +750,44 @@ fn get_string(value: &Value) -> Option<String> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_files() {
+        let mut config = Config::default();
+        assert_eq!(config.input_file("../input"), "../input");
+        assert_eq!(config.session_file_name(), "adventofcode.session");
+
+        config.profile = Some("alice".to_string());
+        assert_eq!(config.input_file("../input"), "../input.alice");
+        assert_eq!(config.session_file_name(), "adventofcode.alice.session");
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        let mut config = Config::default();
+        assert_eq!(
+            unlock_time(&config, 2025, 1).unwrap().to_rfc3339(),
+            "2025-12-01T00:00:00-05:00"
+        );
+        assert!(matches!(
+            unlock_time(&config, 2025, 13),
+            Err(Error::InvalidDay { .. })
+        ));
+        assert!(unlock_time(&config, 2024, 25).is_ok());
+
+        config.utc_offset_hours = 1;
+        config.days = Some(25);
+        assert_eq!(
+            unlock_time(&config, 2025, 25).unwrap().to_rfc3339(),
+            "2025-12-25T00:00:00+01:00"
+        );
+        assert!(matches!(
+            unlock_time(&config, 2025, 0),
+            Err(Error::InvalidDay { .. })
+        ));
+    }
+}
@@ -0,0 +1,225 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use chrono::{Datelike, Utc};
+
+use clap::{Parser, Subcommand};
+
+use aoc::{Config, Error, Result};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code tools")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Encrypt an input file into `<input>.enc`, safe to commit
+    Encrypt { input: PathBuf },
+
+    /// Decrypt `<input>.enc` into an input file
+    Decrypt { input: PathBuf },
+
+    /// Check that the session cookie is still logged in
+    Session,
+
+    /// Download an input, defaulting to the day crate in the current directory
+    Fetch {
+        #[arg(long)]
+        year: Option<i32>,
+
+        #[arg(long)]
+        day: Option<u32>,
+
+        /// Input file, `../input` by default
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Wait for the puzzle to unlock instead of failing
+        #[arg(long)]
+        wait: bool,
+    },
+
+    /// Show the stars and ranking of a private leaderboard
+    Leaderboard {
+        /// Leaderboard id, the number at the end of its URL
+        id: u64,
+
+        /// Event year, by default the one of the day crate in the current directory,
+        /// then the one of the workspace, then the latest event
+        #[arg(long)]
+        year: Option<i32>,
+
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Create the crates of a new day and register them in the workspace
+    New {
+        day: u32,
+
+        /// Event year, `workspace.metadata.aoc.year` by default
+        #[arg(long)]
+        year: Option<i32>,
+
+        /// Stack tier feature of `embedded-aoc` enabling the day
+        #[arg(long, default_value = "stack200k")]
+        stack: String,
+
+        /// Pre-fill the tests with the first example of the puzzle page
+        #[arg(long)]
+        example: bool,
+    },
+}
+
+fn new_day(config: &Config, day: u32, year: Option<i32>, stack: &str, example: bool) -> Result<()> {
+    let root = aoc::workspace_root()?;
+    let year = match year {
+        Some(year) => year,
+        None => aoc::workspace_year(&root)?,
+    };
+
+    let example = if example {
+        let dir = root.join(aoc::day_dir(aoc::workspace_year(&root)?, year, day));
+        let files = aoc::try_get_examples_with(config, year, day, &dir)?;
+        files.first().map(fs::read_to_string).transpose()?
+    } else {
+        None
+    };
+
+    for file in aoc::scaffold_day(&root, year, day, stack, example.as_deref())? {
+        println!("{}", file.display());
+    }
+
+    Ok(())
+}
+
+fn latest_event() -> i32 {
+    let now = Utc::now();
+    if now.month() == 12 {
+        now.year()
+    } else {
+        now.year() - 1
+    }
+}
+
+fn default_year() -> i32 {
+    aoc::cargo_info(Some("../input".to_string()))
+        .map(|info| info.year)
+        .or_else(|_| aoc::workspace_year(aoc::workspace_root()?))
+        .unwrap_or_else(|_| latest_event())
+}
+
+fn leaderboard(config: &Config, id: u64, year: Option<i32>, json: bool) -> Result<()> {
+    let report =
+        aoc::try_get_leaderboard_with(config, year.unwrap_or_else(default_year), id)?.report();
+
+    if json {
+        println!("{}", report.to_json()?);
+    } else {
+        print!("{report}");
+    }
+
+    Ok(())
+}
+
+fn countdown(remaining: Duration) {
+    let secs = remaining.as_secs();
+    eprint!(
+        "\runlocks in {:02}:{:02}:{:02}",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    );
+    io::stderr().flush().ok();
+}
+
+fn fetch(
+    config: Config,
+    year: Option<i32>,
+    day: Option<u32>,
+    input: Option<String>,
+    wait: bool,
+) -> Result<()> {
+    let (config, year, day, input_file) = match (year, day) {
+        (Some(year), Some(day)) => {
+            let input_file = config.input_file(input.as_deref().unwrap_or("../input"));
+            (config, year, day, input_file)
+        }
+        (year, day) => {
+            let info = aoc::cargo_info(Some(input.unwrap_or_else(|| "../input".to_string())))?;
+            (
+                Config {
+                    wait_throttled: config.wait_throttled,
+                    ..info.config
+                },
+                year.unwrap_or(info.year),
+                day.unwrap_or(info.day),
+                info.input_file,
+            )
+        }
+    };
+
+    if wait {
+        aoc::wait_for_unlock(&config, year, day, countdown)?;
+        eprintln!();
+    }
+
+    match aoc::try_get_input_with(&config, year, day, &input_file) {
+        Err(Error::NotYetUnlocked { year, day }) => {
+            let unlock_time = aoc::unlock_time(&config, year, day)?;
+            eprintln!("day {day} of {year} unlocks at {unlock_time}");
+            Err(Error::NotYetUnlocked { year, day })
+        }
+        result => result.map(|()| println!("{input_file}")),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let mut config = Config::from_env();
+    // the commands report a throttled request instead of sleeping it out
+    config.wait_throttled = false;
+
+    let result = match cli.command {
+        Command::Encrypt { input } => aoc::encrypt_file(&config, &input).map(|encrypted| {
+            println!("{} -> {}", input.display(), encrypted.display());
+        }),
+        Command::Decrypt { input } => aoc::decrypt_file(&config, &input).map(|()| {
+            println!(
+                "{} -> {}",
+                aoc::encrypted_file(&input).display(),
+                input.display()
+            );
+        }),
+        Command::Session => aoc::validate_session(&config).map(|()| println!("session valid")),
+        Command::Fetch {
+            year,
+            day,
+            input,
+            wait,
+        } => fetch(config, year, day, input, wait),
+        Command::Leaderboard { id, year, json } => leaderboard(&config, id, year, json),
+        Command::New {
+            day,
+            year,
+            stack,
+            example,
+        } => new_day(&config, day, year, &stack, example),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+
+use serde::{Deserialize, Serialize};
+
+use sha2::{Digest, Sha256};
+
+use crate::{Config, Error, Result, lock, write_if_changed};
+
+/// File name of the manifest, in the directory of the inputs.
+pub const MANIFEST_FILE: &str = "inputs.toml";
+
+/// Provenance of an input file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputEntry {
+    pub year: i32,
+    pub day: u32,
+    pub sha256: String,
+    pub size: u64,
+    /// Download time, missing for inputs that were only decrypted here.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fetched: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+}
+
+/// Entries of the inputs of a directory by file name, e.g. `input` or `input.alice`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Manifest {
+    entries: BTreeMap<String, InputEntry>,
+}
+
+impl Manifest {
+    /// # Errors
+    /// - [`Error::Metadata`] when the manifest is not valid TOML
+    /// - [`Error::Io`] when the manifest exists but cannot be read
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                toml::from_str(&content).map_err(|_| Error::Metadata("invalid input manifest"))
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&InputEntry> {
+        self.entries.get(name)
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, entry: InputEntry) {
+        self.entries.insert(name.into(), entry);
+    }
+}
+
+/// Result of [`check_input`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputCheck {
+    /// The manifest has no entry for the input.
+    Unrecorded,
+    Match(InputEntry),
+    Mismatch {
+        expected: InputEntry,
+        sha256: String,
+        size: u64,
+    },
+}
+
+impl fmt::Display for InputCheck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputCheck::Unrecorded => write!(f, "not in the manifest"),
+            InputCheck::Match(entry) => write!(f, "matches the manifest (sha256 {})", entry.sha256),
+            InputCheck::Mismatch {
+                expected,
+                sha256,
+                size,
+            } => write!(
+                f,
+                "does not match the manifest: sha256 {sha256} ({size} bytes), expected {} ({} bytes)",
+                expected.sha256, expected.size
+            ),
+        }
+    }
+}
+
+/// Path of the manifest of the inputs in the directory of `input_file`.
+#[must_use]
+pub fn manifest_file(input_file: impl AsRef<Path>) -> PathBuf {
+    input_file.as_ref().with_file_name(MANIFEST_FILE)
+}
+
+/// Lowercase hex SHA-256 of `data`.
+#[must_use]
+pub fn sha256(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+fn entry_name(input: &Path) -> Result<&str> {
+    input
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or(Error::Metadata("invalid input file name"))
+}
+
+/// Compare the input file on disk with its manifest entry.
+///
+/// # Errors
+/// - [`Error::Metadata`] when the manifest is invalid
+/// - [`Error::Io`] when the input or the manifest cannot be read
+pub fn check_input(input_file: impl AsRef<Path>) -> Result<InputCheck> {
+    let input = input_file.as_ref();
+    let manifest = Manifest::load(manifest_file(input))?;
+
+    let Some(expected) = manifest.get(entry_name(input)?) else {
+        return Ok(InputCheck::Unrecorded);
+    };
+
+    let data = fs::read(input)?;
+    let sha256 = sha256(&data);
+    if sha256 == expected.sha256 {
+        Ok(InputCheck::Match(expected.clone()))
+    } else {
+        Ok(InputCheck::Mismatch {
+            expected: expected.clone(),
+            sha256,
+            size: data.len() as u64,
+        })
+    }
+}
+
+/// Record the input file on disk in its manifest.
+pub(crate) fn record_input(
+    config: &Config,
+    year: i32,
+    day: u32,
+    input: &Path,
+    fetched: Option<DateTime<Utc>>,
+) -> Result<InputEntry> {
+    let path = manifest_file(input);
+
+    let mut lock_file = path.as_os_str().to_owned();
+    lock_file.push(".lock");
+    let _lock = lock(Path::new(&lock_file))?;
+
+    let data = fs::read(input)?;
+    let entry = InputEntry {
+        year,
+        day,
+        sha256: sha256(&data),
+        size: data.len() as u64,
+        fetched,
+        profile: config.profile.clone(),
+    };
+
+    let mut manifest = Manifest::load(&path)?;
+    manifest.insert(entry_name(input)?, entry.clone());
+
+    let current = fs::read_to_string(&path).ok();
+    let content =
+        toml::to_string(&manifest).map_err(|_| Error::Metadata("invalid input manifest"))?;
+    write_if_changed(&path, current.as_deref(), &content)?;
+
+    Ok(entry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest() {
+        assert_eq!(
+            sha256(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+
+        let mut manifest = Manifest::default();
+        manifest.insert(
+            "input.alice",
+            InputEntry {
+                year: 2025,
+                day: 1,
+                sha256: sha256(b"abc"),
+                size: 3,
+                fetched: "2025-12-01T05:00:03Z".parse().ok(),
+                profile: Some("alice".to_string()),
+            },
+        );
+        manifest.insert(
+            "input",
+            InputEntry {
+                year: 2025,
+                day: 1,
+                sha256: sha256(b""),
+                size: 0,
+                fetched: None,
+                profile: None,
+            },
+        );
+
+        let content = toml::to_string(&manifest).unwrap();
+        assert!(content.contains("[\"input.alice\"]"));
+        assert!(content.contains("fetched = \"2025-12-01T05:00:03Z\""));
+        assert_eq!(toml::from_str::<Manifest>(&content).unwrap(), manifest);
+    }
+}
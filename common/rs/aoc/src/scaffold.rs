@@ -0,0 +1,342 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use toml::{Table, Value};
+
+use crate::{Error, Result};
+
+/// `(path relative to days/dayNN, template)` of every file of a new day.
+const TEMPLATES: &[(&str, &str)] = &[
+    (
+        "rs/Cargo.toml",
+        include_str!("../templates/day/rs/Cargo.toml"),
+    ),
+    ("rs/build.rs", include_str!("../templates/day/rs/build.rs")),
+    (
+        "rs/clippy.toml",
+        include_str!("../templates/day/rs/clippy.toml"),
+    ),
+    (
+        "rs/src/lib.rs",
+        include_str!("../templates/day/rs/src/lib.rs"),
+    ),
+    (
+        "rs/src/main.rs",
+        include_str!("../templates/day/rs/src/main.rs"),
+    ),
+    (
+        "rs/benches/bench.rs",
+        include_str!("../templates/day/rs/benches/bench.rs"),
+    ),
+    (
+        "rsui/Cargo.toml",
+        include_str!("../templates/day/rsui/Cargo.toml"),
+    ),
+    (
+        "rsui/index.html",
+        include_str!("../templates/day/rsui/index.html"),
+    ),
+    (
+        "rsui/index.scss",
+        include_str!("../templates/day/rsui/index.scss"),
+    ),
+    (
+        "rsui/src/main.rs",
+        include_str!("../templates/day/rsui/src/main.rs"),
+    ),
+];
+
+/// Rust raw string literal of `text`, with as many `#` as needed.
+fn raw_string(text: &str) -> String {
+    let mut hashes = String::new();
+    while text.contains(&format!("\"{hashes}")) {
+        hashes.push('#');
+    }
+    format!("r{hashes}\"{text}\"{hashes}")
+}
+
+/// Crate name, `embedded-aoc` variant and directory of a day: `day01`, `Day01` and
+/// `days/day01` for the workspace event, `y2024day01`, `Y2024Day01` and
+/// `years/2024/day01` for the others.
+struct Names {
+    name: String,
+    variant: String,
+    dir: String,
+}
+
+impl Names {
+    fn new(workspace_year: i32, year: i32, day: u32) -> Self {
+        let dir = day_dir(workspace_year, year, day);
+        if year == workspace_year {
+            Self {
+                name: format!("day{day:02}"),
+                variant: format!("Day{day:02}"),
+                dir,
+            }
+        } else {
+            Self {
+                name: format!("y{year}day{day:02}"),
+                variant: format!("Y{year}Day{day:02}"),
+                dir,
+            }
+        }
+    }
+}
+
+/// Folder of a day relative to the workspace root: `days/dayNN` for the
+/// [`workspace_year`] event, `years/<year>/dayNN` for the others.
+#[must_use]
+pub fn day_dir(workspace_year: i32, year: i32, day: u32) -> String {
+    if year == workspace_year {
+        format!("days/day{day:02}")
+    } else {
+        format!("years/{year}/day{day:02}")
+    }
+}
+
+/// Closest ancestor of the current directory with a workspace manifest.
+///
+/// # Errors
+/// - [`Error::Metadata`] when there is no workspace manifest
+/// - [`Error::Io`] when the current directory is not available
+pub fn workspace_root() -> Result<PathBuf> {
+    let current_dir = env::current_dir()?;
+    current_dir
+        .ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .map(Path::to_path_buf)
+        .ok_or(Error::Metadata("cannot find workspace root"))
+}
+
+/// Event of the days in `days/`, `workspace.metadata.aoc.year` of the workspace manifest.
+///
+/// # Errors
+/// - [`Error::Metadata`] when the manifest has no valid year
+/// - [`Error::Io`] when the manifest cannot be read
+pub fn workspace_year(root: impl AsRef<Path>) -> Result<i32> {
+    fs::read_to_string(root.as_ref().join("Cargo.toml"))?
+        .parse::<Table>()
+        .map_err(|_| Error::Metadata("invalid Cargo.toml"))?
+        .get("workspace")
+        .and_then(|value| value.get("metadata"))
+        .and_then(|value| value.get("aoc"))
+        .and_then(|value| value.get("year"))
+        .and_then(Value::as_integer)
+        .and_then(|year| year.try_into().ok())
+        .ok_or(Error::Metadata("cannot find workspace.metadata.aoc.year"))
+}
+
+fn render(template: &str, names: &Names, year: i32, day: u32, example: &str) -> String {
+    template
+        .replace("{{name}}", &names.name)
+        .replace("{{variant}}", &names.variant)
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day_number}}", &day.to_string())
+        .replace("{{day}}", &format!("{day:02}"))
+        .replace("{{input}}", &raw_string(example.trim_end()))
+}
+
+/// Insert `line` before the first line at or after `from` equal to `end`.
+fn insert_before(text: &mut String, from: &str, end: &str, line: &str) -> Result<()> {
+    let start = text
+        .find(from)
+        .ok_or(Error::Metadata("cannot find scaffolding anchor"))?;
+    let position = text[start..]
+        .split_inclusive('\n')
+        .scan(start, |offset, l| {
+            let current = *offset;
+            *offset += l.len();
+            Some((current, l))
+        })
+        .find(|(_, l)| l.trim_end_matches('\n') == end)
+        .map(|(offset, _)| offset)
+        .ok_or(Error::Metadata("cannot find end of scaffolding anchor"))?;
+
+    text.insert_str(position, line);
+    Ok(())
+}
+
+/// Insert `line` after the last line of a day, e.g. `day12 = ...` or `y2024day01 = ...`,
+/// containing `pattern`.
+fn insert_after_last(text: &mut String, pattern: &str, line: &str) -> Result<()> {
+    let position = text
+        .split_inclusive('\n')
+        .scan(0, |offset, l| {
+            *offset += l.len();
+            Some((*offset, l))
+        })
+        .filter(|(_, l)| {
+            (l.starts_with("day") || l.starts_with('y') && l[1..].starts_with(char::is_numeric))
+                && l.contains(pattern)
+        })
+        .last()
+        .map(|(offset, _)| offset)
+        .ok_or(Error::Metadata("cannot find scaffolding anchor"))?;
+
+    text.insert_str(position, line);
+    Ok(())
+}
+
+/// Apply `f` to the file unless it already contains `marker`.
+fn update(path: &Path, marker: &str, f: impl FnOnce(&mut String) -> Result<()>) -> Result<()> {
+    let mut text = fs::read_to_string(path)?;
+    if text.contains(marker) {
+        return Ok(());
+    }
+    f(&mut text)?;
+    fs::write(path, text)?;
+    Ok(())
+}
+
+fn register_workspace(root: &Path, names: &Names) -> Result<()> {
+    let Names { name, dir, .. } = names;
+
+    update(&root.join("Cargo.toml"), &format!("\"{dir}/rs\""), |text| {
+        let members = format!("    \"{dir}/rs\",\n    \"{dir}/rsui\",\n    \n");
+        insert_before(text, "\nmembers = [", "]", &members)?;
+        // a plain `cargo build` builds the default members only, the new day among them
+        if text.contains("\ndefault-members = [") {
+            insert_before(text, "\ndefault-members = [", "]", &members)?;
+        }
+        insert_after_last(
+            text,
+            "default-features = false",
+            &format!("{name} = {{ path = \"{dir}/rs\", default-features = false }}\n"),
+        )
+    })
+}
+
+fn register_embedded(root: &Path, names: &Names, stack: &str) -> Result<()> {
+    let Names {
+        name: day, variant, ..
+    } = names;
+    let embedded = root.join("common/rs/embedded-aoc");
+
+    update(
+        &embedded.join("Cargo.toml"),
+        &format!("\"{day}\""),
+        |text| {
+            insert_after_last(text, "[\"dep:", &format!("{day} = [\"dep:{day}\"]\n"))?;
+            insert_before(
+                text,
+                &format!("\n{stack} = ["),
+                "]",
+                &format!("\n    \"{day}\",\n"),
+            )?;
+            insert_after_last(
+                text,
+                "workspace = true",
+                &format!("{day} = {{ workspace = true, optional = true }}\n"),
+            )
+        },
+    )?;
+
+    update(
+        &embedded.join("src/lib.rs"),
+        &format!("\"{day}\""),
+        |text| {
+            insert_before(
+                text,
+                "days! {",
+                "}",
+                &format!("    #[cfg(feature = \"{day}\")]\n    {variant} => {day}::{variant},\n"),
+            )
+        },
+    )
+}
+
+fn register_runner(root: &Path, names: &Names) -> Result<()> {
+    let Names {
+        name: day, variant, ..
+    } = names;
+    let runner = root.join("common/rs/aoc-run");
+
+    update(
+        &runner.join("Cargo.toml"),
+        &format!("\n{day}.workspace"),
+        |text| {
+            insert_after_last(
+                text,
+                ".workspace = true",
+                &format!("{day}.workspace = true\n"),
+            )
+        },
+    )?;
+
+    update(&runner.join("src/lib.rs"), &format!("<{day}::"), |text| {
+        insert_before(
+            text,
+            "pub const DAYS: &[Day]",
+            "];",
+            &format!("    Day::new::<{day}::{variant}>(),\n"),
+        )
+    })
+}
+
+/// Create the crates of a new day from the templates, in `days/dayNN` for the
+/// [`workspace_year`] event and in `years/<year>/dayNN` for the others, and register
+/// them in the workspace, in `aoc-run` and in `embedded-aoc`, in the given stack tier
+/// feature.
+///
+/// Existing files are left untouched, so it is safe to run again; `example` pre-fills
+/// the input of the test module.
+///
+/// # Errors
+/// - [`Error::InvalidDay`] when `day` is not between 1 and 99
+/// - [`Error::Metadata`] when the workspace or `embedded-aoc` do not have the expected layout
+///   or the workspace has no year
+/// - [`Error::Io`] on file access
+pub fn scaffold_day(
+    root: impl AsRef<Path>,
+    year: i32,
+    day: u32,
+    stack: &str,
+    example: Option<&str>,
+) -> Result<Vec<PathBuf>> {
+    if !(1..100).contains(&day) {
+        return Err(Error::InvalidDay { year, day });
+    }
+
+    let root = root.as_ref();
+    let names = Names::new(workspace_year(root)?, year, day);
+    let dir = root.join(&names.dir);
+
+    let mut files = vec![];
+    for (path, template) in TEMPLATES {
+        let file = dir.join(path);
+        if file.exists() {
+            continue;
+        }
+
+        if let Some(parent) = file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(
+            &file,
+            render(template, &names, year, day, example.unwrap_or("")),
+        )?;
+
+        files.push(file);
+    }
+
+    register_workspace(root, &names)?;
+    register_runner(root, &names)?;
+    register_embedded(root, &names, stack)?;
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_string() {
+        assert_eq!(raw_string("L68\nR48"), "r\"L68\nR48\"");
+        assert_eq!(raw_string("a \"b\""), "r#\"a \"b\"\"#");
+    }
+}
@@ -0,0 +1,455 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::{SecondsFormat, Utc};
+
+use crate::{Config, Error, Result, check_status, http_client, sha256};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited(Duration),
+}
+
+impl Verdict {
+    fn tag(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited(_) => "rate-limited",
+        }
+    }
+
+    fn from_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "correct" => Some(Verdict::Correct),
+            "too-high" => Some(Verdict::TooHigh),
+            "too-low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            "rate-limited" => Some(Verdict::RateLimited(Duration::ZERO)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited(wait) => write!(f, "rate limited ({}s left)", wait.as_secs()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub timestamp: String,
+    pub part: u32,
+    pub verdict: Verdict,
+    /// SHA-256 of the input the answer was computed from, if known.
+    pub input: Option<String>,
+    pub answer: String,
+}
+
+/// Every answer submitted for a day, one tab separated attempt per line.
+#[derive(Debug, Default)]
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+    input: Option<String>,
+}
+
+impl History {
+    /// # Errors
+    /// - [`Error::Io`] when the history file exists but cannot be read
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+
+        let attempts = match fs::read_to_string(&path) {
+            Ok(content) => content.lines().filter_map(parse_attempt).collect(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(Self {
+            path,
+            attempts,
+            input: None,
+        })
+    }
+
+    /// Tie the history to the input with SHA-256 `input`: only attempts on the same
+    /// input, or on an unknown one, decide answers, and new attempts record it.
+    #[must_use]
+    pub fn with_input(mut self, input: Option<String>) -> Self {
+        self.input = input;
+        self
+    }
+
+    #[must_use]
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// The verdict already known for `answer`, if the history rules it in or out.
+    #[must_use]
+    pub fn check(&self, part: u32, answer: &str) -> Option<Verdict> {
+        let attempts = || {
+            self.attempts.iter().filter(move |attempt| {
+                attempt.part == part
+                    && (self.input.is_none()
+                        || attempt.input.is_none()
+                        || attempt.input == self.input)
+            })
+        };
+
+        if let Some(attempt) = attempts().find(|attempt| attempt.verdict == Verdict::Correct) {
+            return Some(if attempt.answer == answer {
+                Verdict::Correct
+            } else {
+                Verdict::Wrong
+            });
+        }
+
+        if let Some(attempt) = attempts().find(|attempt| {
+            attempt.answer == answer && !matches!(attempt.verdict, Verdict::RateLimited(_))
+        }) {
+            return Some(attempt.verdict);
+        }
+
+        let value = answer.parse::<i128>().ok()?;
+        attempts().find_map(|attempt| {
+            let bound = attempt.answer.parse::<i128>().ok()?;
+            match attempt.verdict {
+                Verdict::TooHigh if value >= bound => Some(Verdict::TooHigh),
+                Verdict::TooLow if value <= bound => Some(Verdict::TooLow),
+                _ => None,
+            }
+        })
+    }
+
+    /// # Errors
+    /// - [`Error::Io`] when the history file cannot be written
+    pub fn record(&mut self, part: u32, answer: &str, verdict: Verdict) -> Result<()> {
+        let attempt = Attempt {
+            timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+            part,
+            verdict,
+            input: self.input.clone(),
+            answer: answer.to_string(),
+        };
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        write!(
+            file,
+            "{}\t{}\t{}\t",
+            attempt.timestamp,
+            attempt.part,
+            attempt.verdict.tag()
+        )?;
+        if let Some(input) = &attempt.input {
+            write!(file, "{input}\t")?;
+        }
+        writeln!(file, "{}", attempt.answer)?;
+
+        self.attempts.push(attempt);
+
+        Ok(())
+    }
+}
+
+fn is_sha256(text: &str) -> bool {
+    text.len() == 64 && text.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// `timestamp part verdict [input] answer`, the input checksum is missing in older histories.
+fn parse_attempt(line: &str) -> Option<Attempt> {
+    let mut parts = line.splitn(4, '\t');
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(timestamp), Some(part), Some(verdict), Some(rest)) => {
+            let (input, answer) = match rest.split_once('\t') {
+                Some((input, answer)) if is_sha256(input) => (Some(input.to_string()), answer),
+                _ => (None, rest),
+            };
+            Some(Attempt {
+                timestamp: timestamp.to_string(),
+                part: part.parse().ok()?,
+                verdict: Verdict::from_tag(verdict)?,
+                input,
+                answer: answer.to_string(),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Path of the answer history of a day for the active profile.
+///
+/// # Errors
+/// - [`Error::Metadata`] when no data dir can be found
+pub fn history_file(config: &Config, year: i32, day: u32) -> Result<PathBuf> {
+    let dir = match &config.history_dir {
+        Some(dir) => dir.clone(),
+        None => dirs::data_dir()
+            .ok_or(Error::Metadata("cannot find data dir"))?
+            .join("adventofcode"),
+    };
+
+    let dir = match &config.profile {
+        Some(profile) => dir.join(profile),
+        None => dir,
+    };
+
+    Ok(dir
+        .join(format!("{year}"))
+        .join(format!("day{day:02}.answers")))
+}
+
+fn parse_wait(text: &str) -> Duration {
+    let Some(position) = text.find("You have ") else {
+        return Duration::ZERO;
+    };
+
+    let seconds = text[position + "You have ".len()..]
+        .split_whitespace()
+        .take_while(|token| *token != "left")
+        .map(|token| {
+            let (value, unit) = token.split_at(
+                token
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(token.len()),
+            );
+            let value = value.parse::<u64>().unwrap_or(0);
+            match unit {
+                "h" => value * 3600,
+                "m" => value * 60,
+                _ => value,
+            }
+        })
+        .sum();
+
+    Duration::from_secs(seconds)
+}
+
+/// Parse the answer page returned by the site.
+///
+/// # Errors
+/// - [`Error::UnexpectedResponse`] when the page does not contain a known verdict
+pub fn parse_verdict(page: &str) -> Result<Verdict> {
+    let text = page
+        .find("<article>")
+        .and_then(|start| {
+            page[start..]
+                .find("</article>")
+                .map(|end| &page[start..start + end])
+        })
+        .unwrap_or(page);
+
+    if text.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if text.contains("You gave an answer too recently") {
+        Ok(Verdict::RateLimited(parse_wait(text)))
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Ok(Verdict::TooHigh)
+        } else if text.contains("your answer is too low") {
+            Ok(Verdict::TooLow)
+        } else {
+            Ok(Verdict::Wrong)
+        }
+    } else {
+        Err(Error::UnexpectedResponse)
+    }
+}
+
+/// # Errors
+/// See [`submit_answer_with`].
+pub fn submit_answer(year: i32, day: u32, part: u32, answer: impl fmt::Display) -> Result<Verdict> {
+    submit_answer_with(&Config::from_env(), year, day, part, answer)
+}
+
+/// Submit `answer` unless the day history already rules it out, then record the attempt.
+///
+/// # Errors
+/// - [`Error::InvalidPart`] when `part` is neither 1 nor 2
+/// - [`Error::RuledOut`] when a previous attempt already decided the answer
+/// - [`Error::UnexpectedResponse`] when the answer page cannot be understood
+/// - any transport, session or file error
+pub fn submit_answer_with(
+    config: &Config,
+    year: i32,
+    day: u32,
+    part: u32,
+    answer: impl fmt::Display,
+) -> Result<Verdict> {
+    submit(config, year, day, part, &answer.to_string(), None)
+}
+
+/// Like [`submit_answer_with`], with the answer computed from `input_file`: the
+/// attempt is tied to the input checksum, so attempts on other inputs do not rule it out.
+///
+/// # Errors
+/// See [`submit_answer_with`].
+pub fn submit_answer_for_input(
+    config: &Config,
+    year: i32,
+    day: u32,
+    input_file: impl AsRef<Path>,
+    part: u32,
+    answer: impl fmt::Display,
+) -> Result<Verdict> {
+    let input = sha256(&fs::read(input_file)?);
+    submit(config, year, day, part, &answer.to_string(), Some(input))
+}
+
+fn submit(
+    config: &Config,
+    year: i32,
+    day: u32,
+    part: u32,
+    answer: &str,
+    input: Option<String>,
+) -> Result<Verdict> {
+    if !(1..=2).contains(&part) {
+        return Err(Error::InvalidPart(part));
+    }
+
+    let answer = answer.trim();
+
+    let mut history = History::load(history_file(config, year, day)?)?.with_input(input);
+    if let Some(verdict) = history.check(part, answer) {
+        return match verdict {
+            Verdict::Correct => Ok(Verdict::Correct),
+            verdict => Err(Error::RuledOut {
+                answer: answer.to_string(),
+                verdict,
+            }),
+        };
+    }
+
+    let url = config.url(&format!("/{year}/day/{day}/answer"));
+
+    let page = http_client(config)?
+        .post(url)
+        .form(&[("level", part.to_string().as_str()), ("answer", answer)])
+        .send()
+        .map_err(Error::from)
+        .and_then(|response| check_status(year, day, response))?
+        .text()?;
+
+    let verdict = parse_verdict(&page)?;
+
+    history.record(part, answer, verdict)?;
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_verdict() {
+        assert_eq!(
+            parse_verdict("<main><article><p>That's the right answer! You are one gold star closer.</p></article></main>").unwrap(),
+            Verdict::Correct
+        );
+        assert_eq!(
+            parse_verdict(
+                "<article><p>That's not the right answer; your answer is too high.</p></article>"
+            )
+            .unwrap(),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            parse_verdict(
+                "<article><p>That's not the right answer; your answer is too low.</p></article>"
+            )
+            .unwrap(),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            parse_verdict("<article><p>That's not the right answer.</p></article>").unwrap(),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            parse_verdict("<article><p>You gave an answer too recently. You have 1m 23s left to wait.</p></article>").unwrap(),
+            Verdict::RateLimited(Duration::from_secs(83))
+        );
+        assert!(matches!(
+            parse_verdict(
+                "<article><p>You don't seem to be solving the right level.</p></article>"
+            ),
+            Err(Error::UnexpectedResponse)
+        ));
+    }
+
+    #[test]
+    fn test_history_check() {
+        let history = History {
+            path: PathBuf::new(),
+            attempts: [
+                "1\ttoo-high\t100",
+                "1\ttoo-low\t10",
+                "1\twrong\t42",
+                "2\tcorrect\t7",
+            ]
+            .iter()
+            .filter_map(|line| parse_attempt(&format!("2025-12-01T05:00:00Z\t{line}")))
+            .collect(),
+            input: None,
+        };
+
+        assert_eq!(history.check(1, "100"), Some(Verdict::TooHigh));
+        assert_eq!(history.check(1, "150"), Some(Verdict::TooHigh));
+        assert_eq!(history.check(1, "5"), Some(Verdict::TooLow));
+        assert_eq!(history.check(1, "42"), Some(Verdict::Wrong));
+        assert_eq!(history.check(1, "50"), None);
+        assert_eq!(history.check(2, "7"), Some(Verdict::Correct));
+        assert_eq!(history.check(2, "8"), Some(Verdict::Wrong));
+    }
+
+    #[test]
+    fn test_history_input() {
+        let (a, b) = ("a".repeat(64), "b".repeat(64));
+        let attempts = [
+            format!("1\twrong\t{a}\t42"),
+            format!("1\tcorrect\t{b}\t7"),
+            "2\ttoo-high\t100".to_string(),
+        ]
+        .iter()
+        .filter_map(|line| parse_attempt(&format!("2025-12-01T05:00:00Z\t{line}")))
+        .collect::<Vec<_>>();
+        assert_eq!(attempts[0].input.as_deref(), Some(a.as_str()));
+        assert_eq!(attempts[0].answer, "42");
+        assert_eq!(attempts[2].input, None);
+
+        let history = History {
+            path: PathBuf::new(),
+            attempts,
+            input: Some(a),
+        };
+
+        assert_eq!(history.check(1, "42"), Some(Verdict::Wrong));
+        assert_eq!(history.check(1, "7"), None);
+        assert_eq!(history.check(2, "150"), Some(Verdict::TooHigh));
+    }
+}
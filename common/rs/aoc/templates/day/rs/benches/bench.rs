@@ -0,0 +1,33 @@
+use std::hint;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+
+use {{name}} as day;
+
+const INPUT: &str = include_str!(env!("AOC_INPUT"));
+
+/// Check the answers of the input against the known ones, see `aoc::ANSWERS_FILE`.
+fn check_answers() {
+    if let Some(expected) = option_env!("AOC_ANSWER_1") {
+        assert_eq!(day::part_1(INPUT).to_string(), expected, "part 1 regressed");
+    }
+    if let Some(expected) = option_env!("AOC_ANSWER_2") {
+        assert_eq!(day::part_2(INPUT).to_string(), expected, "part 2 regressed");
+    }
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    check_answers();
+
+    let mut group = c.benchmark_group("{{name}}");
+    group.bench_function("part 1", |b| {
+        b.iter(|| hint::black_box(day::part_1(hint::black_box(INPUT))));
+    });
+    group.bench_function("part 2", |b| {
+        b.iter(|| hint::black_box(day::part_2(hint::black_box(INPUT))));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,4 @@
+fn main() {
+    #[cfg(feature = "input")]
+    aoc::build_input("../input");
+}
@@ -0,0 +1,55 @@
+#![no_std]
+
+/// # Panics
+#[cfg_attr(target_os = "none", inline(never))]
+#[must_use]
+pub fn part_1(_data: &str) -> usize {
+    // placeholder answer until solved, so a new day runs everywhere it is listed
+    0
+}
+
+/// # Panics
+#[cfg_attr(target_os = "none", inline(never))]
+#[must_use]
+pub fn part_2(_data: &str) -> usize {
+    // placeholder answer until solved, so a new day runs everywhere it is listed
+    0
+}
+
+/// Day {{day_number}} of {{year}}.
+pub struct {{variant}};
+
+impl solution::Solution for {{variant}} {
+    const YEAR: u16 = {{year}};
+    const DAY: u8 = {{day_number}};
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn part_1(input: &str) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(input: &str) -> Option<Self::Part2> {
+        Some(part_2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = {{input}};
+
+    #[test]
+    #[ignore = "placeholder answer, fill in the answer of the example"]
+    fn test_part_1() {
+        assert_eq!(part_1(INPUT), 0);
+    }
+
+    #[test]
+    #[ignore = "placeholder answer, fill in the answer of the example"]
+    fn test_part_2() {
+        assert_eq!(part_2(INPUT), 0);
+    }
+}
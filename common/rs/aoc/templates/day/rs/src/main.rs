@@ -0,0 +1,11 @@
+use {{name}} as day;
+
+fn main() {
+    #[cfg(feature = "input")]
+    let input = include_str!(env!("AOC_INPUT"));
+
+    #[cfg(not(feature = "input"))]
+    let input = &std::io::read_to_string(std::io::stdin()).expect("cannot read input");
+
+    report::run::<day::{{variant}}>(input, report::features!());
+}
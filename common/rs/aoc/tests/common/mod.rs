@@ -0,0 +1,125 @@
+#![allow(dead_code)]
+
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use aoc::Config;
+
+pub const SESSION: &str = "53616c7465645f5f";
+
+pub struct Request {
+    pub head: Vec<String>,
+    pub body: String,
+}
+
+/// Serves a single request with `status` and `body`, returning the received request.
+pub fn mock_server(status: &'static str, body: &'static str) -> (String, JoinHandle<Request>) {
+    let (base_url, handle) = mock_server_sequence(&[(status, body)]);
+    (
+        base_url,
+        thread::spawn(move || handle.join().unwrap().pop().unwrap()),
+    )
+}
+
+/// Serves one request for each `(status, body)`, returning the received requests.
+pub fn mock_server_sequence(
+    responses: &[(&'static str, &'static str)],
+) -> (String, JoinHandle<Vec<Request>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("cannot bind mock server");
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+    let responses = responses.to_vec();
+    let handle = thread::spawn(move || {
+        responses
+            .into_iter()
+            .map(|(status, body)| serve(&listener, status, body))
+            .collect()
+    });
+
+    (base_url, handle)
+}
+
+fn serve(listener: &TcpListener, status: &str, body: &str) -> Request {
+    {
+        let (mut stream, _) = listener.accept().expect("cannot accept connection");
+
+        let mut reader = BufReader::new(&mut stream);
+        let head = reader
+            .by_ref()
+            .lines()
+            .map(Result::unwrap)
+            .take_while(|line| !line.is_empty())
+            .collect::<Vec<_>>();
+
+        let length = head
+            .iter()
+            .find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                name.eq_ignore_ascii_case("content-length")
+                    .then(|| value.trim().parse::<usize>().unwrap())
+            })
+            .unwrap_or(0);
+        let mut request_body = vec![0; length];
+        reader.read_exact(&mut request_body).unwrap();
+
+        write!(
+            stream,
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nLocation: /\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+
+        Request {
+            head,
+            body: String::from_utf8(request_body).unwrap(),
+        }
+    }
+}
+
+pub struct Fixture {
+    pub dir: PathBuf,
+    pub config: Config,
+}
+
+impl Fixture {
+    pub fn new(name: &str, base_url: String) -> Self {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{name}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let session_file = dir.join("adventofcode.session");
+        fs::write(&session_file, format!("{SESSION}\n")).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&session_file, fs::Permissions::from_mode(0o600)).unwrap();
+        }
+
+        Self {
+            config: Config {
+                base_url,
+                session_file: Some(session_file),
+                history_dir: Some(dir.join("history")),
+                cache_dir: Some(dir.join("cache")),
+                throttle: Duration::ZERO,
+                retries: 0,
+                retry_delay: Duration::from_millis(10),
+                ..Config::default()
+            },
+            dir,
+        }
+    }
+
+    pub fn input(&self) -> String {
+        self.dir.join("input").display().to_string()
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        fs::remove_dir_all(&self.dir).ok();
+    }
+}
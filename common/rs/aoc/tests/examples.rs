@@ -0,0 +1,93 @@
+mod common;
+
+use std::fs;
+
+use common::{Fixture, mock_server};
+
+const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Not Quite Lisp ---</h2>
+<pre><code>(())
+</code></pre>
+</article>
+</main>"#;
+
+const PAGE_PART_2: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Not Quite Lisp ---</h2>
+<pre><code>(())
+</code></pre>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Now <code>)</code>.</p>
+</article>
+</main>"#;
+
+#[test]
+fn test_get_examples() {
+    let (base_url, server) = mock_server("200 OK", PAGE);
+    let fixture = Fixture::new("examples", base_url);
+
+    let files = aoc::try_get_examples_with(&fixture.config, 2015, 1, &fixture.dir).unwrap();
+
+    assert_eq!(files, vec![fixture.dir.join("example-1-1")]);
+    assert_eq!(fs::read_to_string(&files[0]).unwrap(), "(())\n");
+
+    let request = server.join().unwrap();
+    assert_eq!(request.head[0], "GET /2015/day/1 HTTP/1.1");
+}
+
+#[test]
+fn test_get_examples_cached() {
+    let fixture = Fixture::new("examples-cached", "http://127.0.0.1:1".to_string());
+    fs::write(fixture.dir.join("example-1-1"), "(())\n").unwrap();
+    fs::write(fixture.dir.join("example-2-1"), ")\n").unwrap();
+    fs::write(fixture.dir.join(aoc::EXAMPLES_MARKER), "2\n").unwrap();
+
+    let files = aoc::try_get_examples_with(&fixture.config, 2015, 1, &fixture.dir).unwrap();
+
+    assert_eq!(
+        files,
+        vec![
+            fixture.dir.join("example-1-1"),
+            fixture.dir.join("example-2-1")
+        ]
+    );
+}
+
+#[test]
+fn test_get_examples_part_2_without_examples() {
+    let (base_url, server) = mock_server("200 OK", PAGE_PART_2);
+    let fixture = Fixture::new("examples-part-2", base_url);
+
+    let files = aoc::try_get_examples_with(&fixture.config, 2015, 1, &fixture.dir).unwrap();
+    server.join().unwrap();
+
+    // the server is gone: the second call is served from the saved examples
+    let cached = aoc::try_get_examples_with(&fixture.config, 2015, 1, &fixture.dir).unwrap();
+
+    assert_eq!(files, vec![fixture.dir.join("example-1-1")]);
+    assert_eq!(cached, files);
+}
+
+#[test]
+fn test_get_examples_last_day() {
+    let (base_url, server) = mock_server("200 OK", PAGE);
+    let fixture = Fixture::new("examples-last-day", base_url);
+
+    aoc::try_get_examples_with(&fixture.config, 2015, 25, &fixture.dir).unwrap();
+    server.join().unwrap();
+
+    let files = aoc::try_get_examples_with(&fixture.config, 2015, 25, &fixture.dir).unwrap();
+
+    assert_eq!(files, vec![fixture.dir.join("example-1-1")]);
+}
+
+#[test]
+fn test_get_examples_part_2_locked() {
+    let (base_url, server) = mock_server("200 OK", PAGE);
+    let fixture = Fixture::new("examples-part-2-locked", base_url);
+
+    aoc::try_get_examples_with(&fixture.config, 2015, 1, &fixture.dir).unwrap();
+    server.join().unwrap();
+
+    // only part 1 was on the page: download it again for the examples of part 2
+    assert!(aoc::try_get_examples_with(&fixture.config, 2015, 1, &fixture.dir).is_err());
+}
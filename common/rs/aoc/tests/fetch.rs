@@ -0,0 +1,284 @@
+mod common;
+
+use std::fs;
+use std::time::{Duration, Instant};
+
+use aoc::Error;
+
+use reqwest::StatusCode;
+
+use common::{Fixture, SESSION, mock_server, mock_server_sequence};
+
+#[test]
+fn test_fetch_ok() {
+    let (base_url, server) = mock_server("200 OK", "1\n2\n3\n");
+    let fixture = Fixture::new("ok", base_url);
+
+    aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input()).unwrap();
+
+    assert_eq!(fs::read_to_string(fixture.input()).unwrap(), "1\n2\n3\n");
+
+    let request = server.join().unwrap();
+    assert_eq!(request.head[0], "GET /2015/day/1/input HTTP/1.1");
+    assert!(
+        request
+            .head
+            .iter()
+            .any(|line| line.eq_ignore_ascii_case(&format!("cookie: session={SESSION}")))
+    );
+}
+
+#[test]
+fn test_fetch_manifest() {
+    let (base_url, server) = mock_server("200 OK", "1\n2\n3\n");
+    let mut fixture = Fixture::new("manifest", base_url);
+    fixture.config.profile = Some("alice".to_string());
+    let input = fixture.config.input_file(&fixture.input());
+
+    aoc::try_get_input_with(&fixture.config, 2015, 1, &input).unwrap();
+    server.join().unwrap();
+
+    let aoc::InputCheck::Match(entry) = aoc::check_input(&input).unwrap() else {
+        panic!("input not recorded");
+    };
+    assert_eq!(entry.sha256, aoc::sha256(b"1\n2\n3\n"));
+    assert_eq!(entry.size, 6);
+    assert_eq!((entry.year, entry.day), (2015, 1));
+    assert_eq!(entry.profile.as_deref(), Some("alice"));
+    assert!(entry.fetched.is_some());
+    assert!(
+        fs::read_to_string(fixture.dir.join(aoc::MANIFEST_FILE))
+            .unwrap()
+            .contains("[\"input.alice\"]")
+    );
+
+    fs::write(&input, "1\n2\n").unwrap();
+    assert!(matches!(
+        aoc::check_input(&input).unwrap(),
+        aoc::InputCheck::Mismatch { size: 4, .. }
+    ));
+    assert_eq!(
+        aoc::check_input(fixture.input()).unwrap(),
+        aoc::InputCheck::Unrecorded
+    );
+}
+
+#[test]
+fn test_fetch_existing_input() {
+    let fixture = Fixture::new("existing", "http://127.0.0.1:1".to_string());
+    fs::write(fixture.input(), "cached").unwrap();
+
+    aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input()).unwrap();
+
+    assert_eq!(fs::read_to_string(fixture.input()).unwrap(), "cached");
+}
+
+#[test]
+fn test_fetch_disabled() {
+    let mut fixture = Fixture::new("disabled", "http://127.0.0.1:1".to_string());
+    fixture.config.fetch = false;
+
+    let result = aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input());
+
+    assert!(matches!(
+        result,
+        Err(Error::FetchDisabled { year: 2015, day: 1 })
+    ));
+    assert!(fs::read_to_string(fixture.input()).is_err());
+}
+
+#[test]
+fn test_fetch_session_redirect() {
+    let (base_url, server) = mock_server("302 Found", "");
+    let fixture = Fixture::new("redirect", base_url);
+
+    let result = aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input());
+
+    assert!(matches!(result, Err(Error::SessionExpired)));
+    assert!(fs::read_to_string(fixture.input()).is_err());
+    server.join().unwrap();
+}
+
+#[test]
+fn test_fetch_session_bad_request() {
+    let (base_url, server) = mock_server("400 Bad Request", "Please log in.");
+    let fixture = Fixture::new("bad-request", base_url);
+
+    let result = aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input());
+
+    assert!(matches!(
+        result,
+        Err(Error::SessionRejected(StatusCode::BAD_REQUEST))
+    ));
+    server.join().unwrap();
+}
+
+#[test]
+fn test_fetch_locked_day() {
+    let (base_url, server) = mock_server("404 Not Found", "Not Found");
+    let fixture = Fixture::new("locked", base_url);
+
+    let result = aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input());
+
+    assert!(matches!(
+        result,
+        Err(Error::NotYetUnlocked { year: 2015, day: 1 })
+    ));
+    assert!(aoc::locked_file(fixture.input()).exists());
+    server.join().unwrap();
+}
+
+#[test]
+fn test_fetch_future_day() {
+    let fixture = Fixture::new("future", "http://127.0.0.1:1".to_string());
+
+    let result = aoc::try_get_input_with(&fixture.config, 2999, 1, &fixture.input());
+
+    assert!(matches!(
+        result,
+        Err(Error::NotYetUnlocked { year: 2999, day: 1 })
+    ));
+    assert!(fs::read_to_string(fixture.input()).is_err());
+    assert!(
+        fs::read_to_string(aoc::locked_file(fixture.input()))
+            .unwrap()
+            .starts_with("day 1 of 2999 unlocks at 2999-12-01T00:00:00-05:00")
+    );
+}
+
+#[test]
+fn test_fetch_unlocked_removes_marker() {
+    let (base_url, server) = mock_server("200 OK", "1\n2\n3\n");
+    let fixture = Fixture::new("unlocked", base_url);
+    fs::write(aoc::locked_file(fixture.input()), "locked").unwrap();
+
+    aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input()).unwrap();
+
+    assert!(!aoc::locked_file(fixture.input()).exists());
+    server.join().unwrap();
+}
+
+#[test]
+fn test_fetch_invalid_day() {
+    let fixture = Fixture::new("invalid-day", "http://127.0.0.1:1".to_string());
+
+    let result = aoc::try_get_input_with(&fixture.config, 2025, 13, &fixture.input());
+
+    assert!(matches!(
+        result,
+        Err(Error::InvalidDay {
+            year: 2025,
+            day: 13
+        })
+    ));
+}
+
+#[test]
+fn test_fetch_server_error() {
+    let (base_url, server) = mock_server("500 Internal Server Error", "");
+    let fixture = Fixture::new("server-error", base_url);
+
+    let result = aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input());
+
+    assert!(matches!(
+        result,
+        Err(Error::HttpStatus(StatusCode::INTERNAL_SERVER_ERROR))
+    ));
+    server.join().unwrap();
+}
+
+#[test]
+fn test_missing_session() {
+    let mut fixture = Fixture::new("missing-session", "http://127.0.0.1:1".to_string());
+    fixture.config.session_file = Some(fixture.dir.join("missing.session"));
+
+    let result = aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input());
+
+    assert!(matches!(result, Err(Error::SessionMissing(_))));
+}
+
+#[test]
+fn test_decrypt_input() {
+    let mut fixture = Fixture::new("decrypt", "http://127.0.0.1:1".to_string());
+    let key_file = fixture.dir.join("adventofcode.key");
+    fs::write(&key_file, format!("{}\n", "07".repeat(32))).unwrap();
+    fixture.config.key_file = Some(key_file);
+
+    fs::write(
+        aoc::encrypted_file(fixture.input()),
+        aoc::encrypt(&[7; 32], b"1\n2\n3\n"),
+    )
+    .unwrap();
+
+    aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input()).unwrap();
+
+    assert_eq!(fs::read_to_string(fixture.input()).unwrap(), "1\n2\n3\n");
+}
+
+#[test]
+fn test_fetch_retry_server_error() {
+    let (base_url, server) = mock_server_sequence(&[
+        ("502 Bad Gateway", ""),
+        ("503 Service Unavailable", ""),
+        ("200 OK", "1\n2\n3\n"),
+    ]);
+    let mut fixture = Fixture::new("retry", base_url);
+    fixture.config.retries = 2;
+
+    aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input()).unwrap();
+
+    assert_eq!(fs::read_to_string(fixture.input()).unwrap(), "1\n2\n3\n");
+    assert_eq!(server.join().unwrap().len(), 3);
+}
+
+#[test]
+fn test_fetch_throttled() {
+    let (base_url, server) = mock_server("500 Internal Server Error", "");
+    let mut fixture = Fixture::new("throttled", base_url);
+    fixture.config.throttle = Duration::from_mins(1);
+    fixture.config.wait_throttled = false;
+
+    let result = aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input());
+    assert!(matches!(result, Err(Error::HttpStatus(_))));
+    server.join().unwrap();
+
+    let result = aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input());
+    assert!(matches!(result, Err(Error::Throttled { .. })));
+}
+
+#[test]
+fn test_fetch_throttle_wait() {
+    let (base_url, server) =
+        mock_server_sequence(&[("500 Internal Server Error", ""), ("200 OK", "1\n2\n3\n")]);
+    let mut fixture = Fixture::new("throttle-wait", base_url);
+    fixture.config.throttle = Duration::from_millis(200);
+
+    let result = aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input());
+    assert!(matches!(result, Err(Error::HttpStatus(_))));
+
+    let now = Instant::now();
+    aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input()).unwrap();
+    assert!(now.elapsed() >= Duration::from_millis(100));
+
+    assert_eq!(fs::read_to_string(fixture.input()).unwrap(), "1\n2\n3\n");
+    assert_eq!(server.join().unwrap().len(), 2);
+}
+
+#[test]
+fn test_missing_session_not_throttled() {
+    let (base_url, server) = mock_server("200 OK", "1\n2\n3\n");
+    let mut fixture = Fixture::new("missing-session-throttle", base_url);
+    fixture.config.throttle = Duration::from_mins(1);
+    fixture.config.wait_throttled = false;
+    let session_file = fixture.config.session_file.clone();
+    fixture.config.session_file = Some(fixture.dir.join("missing.session"));
+
+    let result = aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input());
+    assert!(matches!(result, Err(Error::SessionMissing(_))));
+
+    fixture.config.session_file = session_file;
+    aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input()).unwrap();
+
+    assert_eq!(fs::read_to_string(fixture.input()).unwrap(), "1\n2\n3\n");
+    server.join().unwrap();
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use std::fs;
+
+use aoc::Error;
+
+use reqwest::StatusCode;
+
+use common::{Fixture, mock_server};
+
+const LEADERBOARD: &str = r#"{"event":"2025","owner_id":1,"members":{"1":{"id":1,"name":"alice","stars":2,"local_score":2,"last_star_ts":1764565510,"completion_day_level":{"1":{"1":{"get_star_ts":1764565380,"star_index":1},"2":{"get_star_ts":1764565510,"star_index":2}}}}}}"#;
+
+#[test]
+fn test_leaderboard_cached() {
+    let (base_url, server) = mock_server("200 OK", LEADERBOARD);
+    let fixture = Fixture::new("leaderboard", base_url);
+
+    let leaderboard = aoc::try_get_leaderboard_with(&fixture.config, 2025, 1).unwrap();
+    assert_eq!(leaderboard.members["1"].stars, 2);
+
+    let request = server.join().unwrap();
+    assert_eq!(
+        request.head[0],
+        "GET /2025/leaderboard/private/view/1.json HTTP/1.1"
+    );
+    assert!(fixture.dir.join("cache/leaderboard/2025-1.json").exists());
+
+    // the server is gone: a second request within the polling interval uses the cache
+    assert_eq!(
+        aoc::try_get_leaderboard_with(&fixture.config, 2025, 1).unwrap(),
+        leaderboard
+    );
+}
+
+#[test]
+fn test_leaderboard_stale_cache() {
+    let (base_url, server) = mock_server("200 OK", LEADERBOARD);
+    let fixture = Fixture::new("leaderboard-stale", base_url);
+
+    let cache = fixture.dir.join("cache/leaderboard/2025-1.json");
+    fs::create_dir_all(cache.parent().unwrap()).unwrap();
+    fs::write(&cache, "{}").unwrap();
+
+    let leaderboard = aoc::try_get_leaderboard_with(&fixture.config, 2025, 1).unwrap();
+    assert_eq!(leaderboard.event, "2025");
+    assert_eq!(fs::read_to_string(&cache).unwrap(), LEADERBOARD);
+    server.join().unwrap();
+}
+
+#[test]
+fn test_leaderboard_not_found() {
+    let (base_url, server) = mock_server("404 Not Found", "");
+    let fixture = Fixture::new("leaderboard-not-found", base_url);
+
+    assert!(matches!(
+        aoc::try_get_leaderboard_with(&fixture.config, 2025, 1),
+        Err(Error::HttpStatus(StatusCode::NOT_FOUND))
+    ));
+    server.join().unwrap();
+}
@@ -0,0 +1,145 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+struct Workspace(PathBuf);
+
+impl Workspace {
+    /// Copy of the manifests touched by the scaffolding.
+    fn new(name: &str) -> Self {
+        let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../..");
+        let root = std::env::temp_dir().join(format!("aoc-{}-{name}", std::process::id()));
+
+        for file in [
+            "Cargo.toml",
+            "common/rs/aoc-run/Cargo.toml",
+            "common/rs/aoc-run/src/lib.rs",
+            "common/rs/embedded-aoc/Cargo.toml",
+            "common/rs/embedded-aoc/src/lib.rs",
+        ] {
+            fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+            fs::copy(source.join(file), root.join(file)).unwrap();
+        }
+
+        Self(root)
+    }
+}
+
+impl Drop for Workspace {
+    fn drop(&mut self) {
+        fs::remove_dir_all(&self.0).ok();
+    }
+}
+
+#[test]
+fn test_scaffold_day() {
+    let workspace = Workspace::new("scaffold");
+    let root = &workspace.0;
+
+    let files = aoc::scaffold_day(root, 2025, 42, "stack1k", Some("1 \"2\"\n3\n")).unwrap();
+    assert_eq!(files.len(), 10);
+
+    let manifest = fs::read_to_string(root.join("days/day42/rs/Cargo.toml")).unwrap();
+    assert!(manifest.contains("name = \"day42\""));
+    assert!(manifest.contains("year = 2025\nday = 42\n"));
+
+    let lib = fs::read_to_string(root.join("days/day42/rs/src/lib.rs")).unwrap();
+    assert!(lib.contains("const INPUT: &str = r#\"1 \"2\"\n3\"#;"));
+    assert!(lib.contains("impl solution::Solution for Day42 {"));
+    assert!(lib.contains("const YEAR: u16 = 2025;\n    const DAY: u8 = 42;"));
+
+    let ui = fs::read_to_string(root.join("days/day42/rsui/src/main.rs")).unwrap();
+    assert!(ui.contains("ui::render::<day42::Day42>();"));
+
+    let workspace_manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    let (members, default_members) = workspace_manifest
+        .split_once("\ndefault-members = [")
+        .unwrap();
+    for members in [members, default_members] {
+        assert!(members.contains("    \"days/day42/rs\",\n    \"days/day42/rsui\",\n    \n]"));
+    }
+    assert!(
+        workspace_manifest
+            .contains("day42 = { path = \"days/day42/rs\", default-features = false }\n")
+    );
+
+    let runner_manifest = fs::read_to_string(root.join("common/rs/aoc-run/Cargo.toml")).unwrap();
+    assert!(runner_manifest.contains("day12.workspace = true\nday42.workspace = true\n"));
+
+    let runner_lib = fs::read_to_string(root.join("common/rs/aoc-run/src/lib.rs")).unwrap();
+    assert!(runner_lib.contains("    Day::new::<day42::Day42>(),\n];"));
+
+    let embedded_manifest =
+        fs::read_to_string(root.join("common/rs/embedded-aoc/Cargo.toml")).unwrap();
+    assert!(embedded_manifest.contains("day42 = [\"dep:day42\"]\n"));
+    assert!(embedded_manifest.contains("day42 = { workspace = true, optional = true }\n"));
+    assert!(embedded_manifest.contains("\n    \"day42\",\n]\n\n[dependencies]"));
+
+    let embedded_lib = fs::read_to_string(root.join("common/rs/embedded-aoc/src/lib.rs")).unwrap();
+    assert!(
+        embedded_lib.contains("    #[cfg(feature = \"day42\")]\n    Day42 => day42::Day42,\n}")
+    );
+
+    // running it again changes nothing
+    assert!(
+        aoc::scaffold_day(root, 2025, 42, "stack1k", None)
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+        workspace_manifest
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("common/rs/aoc-run/src/lib.rs")).unwrap(),
+        runner_lib
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("common/rs/embedded-aoc/src/lib.rs")).unwrap(),
+        embedded_lib
+    );
+}
+
+#[test]
+fn test_scaffold_other_year() {
+    let workspace = Workspace::new("scaffold-year");
+    let root = &workspace.0;
+
+    assert_eq!(aoc::workspace_year(root).unwrap(), 2025);
+
+    aoc::scaffold_day(root, 2024, 1, "stack1k", None).unwrap();
+
+    let manifest = fs::read_to_string(root.join("years/2024/day01/rs/Cargo.toml")).unwrap();
+    assert!(manifest.contains("name = \"y2024day01\""));
+    assert!(manifest.contains("year = 2024\nday = 1\n"));
+
+    let workspace_manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    assert!(workspace_manifest.contains("    \"years/2024/day01/rs\",\n"));
+    assert!(
+        workspace_manifest.contains(
+            "y2024day01 = { path = \"years/2024/day01/rs\", default-features = false }\n"
+        )
+    );
+
+    let runner_lib = fs::read_to_string(root.join("common/rs/aoc-run/src/lib.rs")).unwrap();
+    assert!(runner_lib.contains("Day::new::<y2024day01::Y2024Day01>(),"));
+
+    let embedded_manifest =
+        fs::read_to_string(root.join("common/rs/embedded-aoc/Cargo.toml")).unwrap();
+    assert!(embedded_manifest.contains("y2024day01 = [\"dep:y2024day01\"]\n"));
+
+    let lib = fs::read_to_string(root.join("years/2024/day01/rs/src/lib.rs")).unwrap();
+    assert!(lib.contains("impl solution::Solution for Y2024Day01 {"));
+
+    let embedded_lib = fs::read_to_string(root.join("common/rs/embedded-aoc/src/lib.rs")).unwrap();
+    assert!(embedded_lib.contains("    Y2024Day01 => y2024day01::Y2024Day01,\n}"));
+}
+
+#[test]
+fn test_scaffold_invalid_day() {
+    let workspace = Workspace::new("scaffold-invalid");
+
+    assert!(matches!(
+        aoc::scaffold_day(&workspace.0, 2025, 100, "stack1k", None),
+        Err(aoc::Error::InvalidDay { .. })
+    ));
+}
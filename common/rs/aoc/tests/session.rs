@@ -0,0 +1,83 @@
+mod common;
+
+use aoc::{Error, Session};
+
+use reqwest::StatusCode;
+
+use common::{Fixture, mock_server};
+
+#[test]
+fn test_session_from_config() {
+    let (base_url, server) = mock_server("200 OK", "1\n");
+    let mut fixture = Fixture::new("session-env", base_url);
+    fixture.config.session_file = Some(fixture.dir.join("missing.session"));
+    fixture.config.session = Some(Session::new("cafebabe\n"));
+
+    aoc::try_get_input_with(&fixture.config, 2015, 1, &fixture.input()).unwrap();
+
+    let request = server.join().unwrap();
+    assert!(
+        request
+            .head
+            .iter()
+            .any(|line| line.eq_ignore_ascii_case("cookie: session=cafebabe"))
+    );
+}
+
+#[test]
+fn test_validate_session_ok() {
+    let (base_url, server) = mock_server("200 OK", "<a href=\"/2025/auth/logout\">[Log Out]</a>");
+    let fixture = Fixture::new("validate-ok", base_url);
+
+    aoc::validate_session(&fixture.config).unwrap();
+
+    assert_eq!(server.join().unwrap().head[0], "GET /settings HTTP/1.1");
+}
+
+#[test]
+fn test_validate_session_redirect() {
+    let (base_url, server) = mock_server("302 Found", "");
+    let fixture = Fixture::new("validate-redirect", base_url);
+
+    assert!(matches!(
+        aoc::validate_session(&fixture.config),
+        Err(Error::SessionExpired)
+    ));
+    server.join().unwrap();
+}
+
+#[test]
+fn test_validate_session_login_page() {
+    let (base_url, server) = mock_server("200 OK", "<a href=\"/2025/auth/login\">[Log In]</a>");
+    let fixture = Fixture::new("validate-login", base_url);
+
+    assert!(matches!(
+        aoc::validate_session(&fixture.config),
+        Err(Error::SessionExpired)
+    ));
+    server.join().unwrap();
+}
+
+#[test]
+fn test_validate_session_rejected() {
+    let (base_url, server) = mock_server("400 Bad Request", "");
+    let fixture = Fixture::new("validate-rejected", base_url);
+
+    assert!(matches!(
+        aoc::validate_session(&fixture.config),
+        Err(Error::SessionRejected(StatusCode::BAD_REQUEST))
+    ));
+    server.join().unwrap();
+}
+
+#[test]
+fn test_session_never_shown() {
+    let mut fixture = Fixture::new("session-hidden", "http://127.0.0.1:1".to_string());
+    fixture.config.session = Some(Session::new("secret\u{1}cookie"));
+
+    assert!(!format!("{:?}", fixture.config).contains("secret"));
+
+    let err = aoc::validate_session(&fixture.config).unwrap_err();
+    assert!(!err.to_string().contains("secret"));
+    assert!(!format!("{err:?}").contains("secret"));
+}
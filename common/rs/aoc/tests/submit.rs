@@ -0,0 +1,73 @@
+mod common;
+
+use std::time::Duration;
+
+use aoc::{Error, History, Verdict};
+
+use common::{Fixture, mock_server};
+
+const CORRECT: &str = "<main><article><p>That's the right answer! You are one gold star closer to decorating the North Pole.</p></article></main>";
+const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.</p></article></main>";
+const RATE_LIMITED: &str = "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 45s left to wait.</p></article></main>";
+
+#[test]
+fn test_submit_correct() {
+    let (base_url, server) = mock_server("200 OK", CORRECT);
+    let fixture = Fixture::new("submit-correct", base_url);
+
+    let verdict = aoc::submit_answer_with(&fixture.config, 2015, 1, 1, 138).unwrap();
+    assert_eq!(verdict, Verdict::Correct);
+
+    let request = server.join().unwrap();
+    assert_eq!(request.head[0], "POST /2015/day/1/answer HTTP/1.1");
+    assert_eq!(request.body, "level=1&answer=138");
+
+    // known correct answer: no request
+    let verdict = aoc::submit_answer_with(&fixture.config, 2015, 1, 1, 138).unwrap();
+    assert_eq!(verdict, Verdict::Correct);
+
+    let history = History::load(aoc::history_file(&fixture.config, 2015, 1).unwrap()).unwrap();
+    assert_eq!(history.attempts().len(), 1);
+}
+
+#[test]
+fn test_submit_ruled_out() {
+    let (base_url, server) = mock_server("200 OK", TOO_HIGH);
+    let fixture = Fixture::new("submit-ruled-out", base_url);
+
+    let verdict = aoc::submit_answer_with(&fixture.config, 2015, 1, 2, 1000).unwrap();
+    assert_eq!(verdict, Verdict::TooHigh);
+    server.join().unwrap();
+
+    for answer in [1000, 2000] {
+        let result = aoc::submit_answer_with(&fixture.config, 2015, 1, 2, answer);
+        assert!(matches!(
+            result,
+            Err(Error::RuledOut {
+                verdict: Verdict::TooHigh,
+                ..
+            })
+        ));
+    }
+}
+
+#[test]
+fn test_submit_rate_limited() {
+    let (base_url, server) = mock_server("200 OK", RATE_LIMITED);
+    let fixture = Fixture::new("submit-rate-limited", base_url);
+
+    let verdict = aoc::submit_answer_with(&fixture.config, 2015, 1, 1, 42).unwrap();
+    assert_eq!(verdict, Verdict::RateLimited(Duration::from_secs(45)));
+    server.join().unwrap();
+
+    let history = History::load(aoc::history_file(&fixture.config, 2015, 1).unwrap()).unwrap();
+    assert!(history.check(1, "42").is_none());
+}
+
+#[test]
+fn test_submit_invalid_part() {
+    let fixture = Fixture::new("submit-invalid-part", "http://127.0.0.1:1".to_string());
+
+    let result = aoc::submit_answer_with(&fixture.config, 2015, 1, 3, 42);
+    assert!(matches!(result, Err(Error::InvalidPart(3))));
+}
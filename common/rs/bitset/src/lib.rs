@@ -16,10 +16,11 @@ pub struct BitSet<T, K, const SIZE: usize> {
 }
 
 impl BitSet<(), fn(&()) -> usize, 0> {
+    #[must_use]
     pub const fn with_capacity(size: usize) -> usize {
         debug_assert!(size > 0, "invalid size");
         
-        size / BITS + if size % BITS == 0 { 0 } else { 1 }
+        size.div_ceil(BITS)
     }
 }
 
@@ -45,6 +46,8 @@ impl<T, K: Fn(&T) -> usize, const SIZE: usize> BitSet<T, K, SIZE> {
         }
     }
 
+    /// # Errors
+    /// - [`Error`] when the key of `idx` is out of the capacity
     #[allow(clippy::needless_pass_by_value)]
     pub fn insert(&mut self, idx: T) -> Result<bool, Error> {
         let idx = (self.key)(&idx);
@@ -61,6 +64,8 @@ impl<T, K: Fn(&T) -> usize, const SIZE: usize> BitSet<T, K, SIZE> {
         Ok(result)
     }
 
+    /// # Errors
+    /// - [`Error`] when the key of `idx` is out of the capacity
     pub fn contains(&self, idx: &T) -> Result<bool, Error> {
         let idx = (self.key)(idx);
         let (i, b) = (idx / BITS, idx % BITS);
@@ -68,6 +73,8 @@ impl<T, K: Fn(&T) -> usize, const SIZE: usize> BitSet<T, K, SIZE> {
         Ok(self.data.get(i).ok_or(Error)? & (1 << b) != 0)
     }
 
+    /// # Errors
+    /// - [`Error`] when the key of `idx` is out of the capacity
     pub fn remove(&mut self, idx: &T) -> Result<bool, Error> {
         let idx = (self.key)(idx);
         let (i, b) = (idx / BITS, idx % BITS);
@@ -1,6 +1,5 @@
 #![no_std]
 
-#[cfg(any(feature = "blocking", feature = "nonblocking", feature = "minimal"))]
 use core::fmt;
 
 use core::marker;
@@ -30,8 +29,14 @@ mod minimal;
 #[cfg(feature = "minimal")]
 pub use minimal::run;
 
-#[allow(dead_code)]
-type PartResult = HLString<64>;
+/// Bytes of the answer of a part.
+pub const RESULT_LEN: usize = 64;
+
+/// Answer sent back for a day without part 2.
+pub const NO_PART_2: &str = "NO PART 2";
+
+/// Answer of a part as sent back, [`NO_PART_2`] for a day without part 2.
+pub type PartResult = HLString<RESULT_LEN>;
 
 #[cfg(any(feature = "blocking", feature = "nonblocking", feature = "minimal"))]
 const START_INPUT_TAG: &str = "START INPUT DAY: ";
@@ -39,182 +44,161 @@ const START_INPUT_TAG: &str = "START INPUT DAY: ";
 #[cfg(any(feature = "blocking", feature = "nonblocking", feature = "minimal"))]
 const END_INPUT_TAG: &str = "END INPUT";
 
-#[cfg(feature = "buffer25k")]
+#[cfg(all(
+    feature = "buffer25k",
+    any(feature = "blocking", feature = "nonblocking", feature = "minimal")
+))]
 const BUFFER_SIZE: usize = 25 * 1024;
 
-#[cfg(feature = "buffer1k")]
+#[cfg(all(
+    feature = "buffer1k",
+    any(feature = "blocking", feature = "nonblocking", feature = "minimal")
+))]
 const BUFFER_SIZE: usize = 1024;
 
+/// Year of the days whose header has no `YYYY-` prefix, the event of the workspace.
+pub const DEFAULT_YEAR: u16 = match u16::from_str_radix(env!("AOC_WORKSPACE_YEAR"), 10) {
+    Ok(year) => year,
+    Err(_) => panic!("invalid AOC_WORKSPACE_YEAR"),
+};
+
+/// Split the request after [`START_INPUT_TAG`] into the `[YYYY-]XY` header and the input.
+#[cfg(any(feature = "blocking", feature = "nonblocking", feature = "minimal"))]
+fn split_header(request: &str) -> (&str, &str) {
+    request.split_once('\n').unwrap_or((request, ""))
+}
+
 #[must_use]
 pub fn check_eof(buffer: &[u8]) -> Option<usize> {
     buffer.iter().position(|b| *b == 0x04)
 }
 
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
-#[derive(Copy, Clone)]
-pub enum Day {
+/// Float of the day 10 simplex, `f64` when both `f32` and `f64` are enabled.
+#[cfg(all(feature = "day10", feature = "f64"))]
+type Float = f64;
+
+#[cfg(all(feature = "day10", feature = "f32", not(feature = "f64")))]
+type Float = f32;
+
+fn part_1<S: solution::Solution>(result: &mut PartResult, input: &str) -> Result<(), fmt::Error> {
+    fmt::write(result, format_args!("{}", S::part_1(input)))
+}
+
+fn part_2<S: solution::Solution>(result: &mut PartResult, input: &str) -> Result<(), fmt::Error> {
+    match S::part_2(input) {
+        Some(answer) => fmt::write(result, format_args!("{answer}")),
+        None => fmt::write(result, format_args!("{NO_PART_2}")),
+    }
+}
+
+/// `Day` with a variant for each `Variant => Solution` entry, enabled by its `cfg`.
+macro_rules! days {
+    ($(#[$cfg:meta] $variant:ident => $solution:ty,)*) => {
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        #[derive(Copy, Clone)]
+        pub enum Day {
+            $(#[$cfg] $variant,)*
+        }
+
+        impl Day {
+            /// Every day enabled by the features.
+            pub const ALL: &[Day] = &[$(#[$cfg] Day::$variant,)*];
+
+            /// Year and day of the puzzle.
+            #[must_use]
+            pub const fn id(self) -> (u16, u8) {
+                match self {
+                    $(#[$cfg] Day::$variant => (
+                        <$solution as solution::Solution>::YEAR,
+                        <$solution as solution::Solution>::DAY,
+                    ),)*
+                }
+            }
+
+            /// Answer of part 1 in `result`.
+            ///
+            /// # Errors
+            /// When the answer does not fit `result`.
+            pub fn part_1(self, result: &mut PartResult, input: &str) -> Result<(), fmt::Error> {
+                match self {
+                    $(#[$cfg] Day::$variant => part_1::<$solution>(result, input),)*
+                }
+            }
+
+            /// Answer of part 2 in `result`, [`NO_PART_2`] for a day without part 2.
+            ///
+            /// # Errors
+            /// When the answer does not fit `result`.
+            pub fn part_2(self, result: &mut PartResult, input: &str) -> Result<(), fmt::Error> {
+                match self {
+                    $(#[$cfg] Day::$variant => part_2::<$solution>(result, input),)*
+                }
+            }
+        }
+    };
+}
+
+days! {
     #[cfg(feature = "day01")]
-    Day01,
+    Day01 => day01::Day01,
     #[cfg(feature = "day02")]
-    Day02,
+    Day02 => day02::Day02,
     #[cfg(feature = "day03")]
-    Day03,
+    Day03 => day03::Day03,
     #[cfg(feature = "day04")]
-    Day04,
+    Day04 => day04::Day04,
     #[cfg(feature = "day05")]
-    Day05,
+    Day05 => day05::Day05,
     #[cfg(feature = "day06")]
-    Day06,
+    Day06 => day06::Day06,
     #[cfg(feature = "day07")]
-    Day07,
+    Day07 => day07::Day07,
     #[cfg(feature = "day08")]
-    Day08,
+    Day08 => day08::Day08,
     #[cfg(feature = "day09")]
-    Day09,
+    Day09 => day09::Day09,
     #[cfg(all(feature = "day10", any(feature = "f32", feature = "f64")))]
-    Day10,
+    Day10 => day10::Day10<Float>,
     #[cfg(feature = "day11")]
-    Day11,
+    Day11 => day11::Day11,
     #[cfg(feature = "day12")]
-    Day12,
-}
-
-#[cfg(any(feature = "blocking", feature = "nonblocking", feature = "minimal"))]
-impl Day {
-    fn to_string(result: &mut PartResult, value: impl fmt::Display) -> Result<(), fmt::Error> {
-        fmt::write(result, format_args!("{value}"))
-    }
-
-    fn part_1(self, result: &mut PartResult, input: &str) -> Result<(), fmt::Error> {
-        match self {
-            #[cfg(feature = "day01")]
-            Day::Day01 => Self::to_string(result, day01::part_1(input)),
-            #[cfg(feature = "day02")]
-            Day::Day02 => Self::to_string(result, day02::part_1(input)),
-            #[cfg(feature = "day03")]
-            Day::Day03 => Self::to_string(result, day03::part_1(input)),
-            #[cfg(feature = "day04")]
-            Day::Day04 => Self::to_string(result, day04::part_1(input)),
-            #[cfg(feature = "day05")]
-            Day::Day05 => Self::to_string(result, day05::part_1(input)),
-            #[cfg(feature = "day06")]
-            Day::Day06 => Self::to_string(result, day06::part_1(input)),
-            #[cfg(feature = "day07")]
-            Day::Day07 => Self::to_string(result, day07::part_1(input)),
-            #[cfg(feature = "day08")]
-            Day::Day08 => Self::to_string(result, day08::part_1::<1000>(input)),
-            #[cfg(feature = "day09")]
-            Day::Day09 => Self::to_string(result, day09::part_1(input)),
-            #[cfg(all(feature = "day10", any(feature = "f32", feature = "f64")))]
-            Day::Day10 => Self::to_string(result, day10::part_1(input)),
-            #[cfg(feature = "day11")]
-            Day::Day11 => Self::to_string(result, day11::part_1(input)),
-            #[cfg(feature = "day12")]
-            Day::Day12 => Self::to_string(result, day12::part_1(input)),
-        }
-    }
-
-    fn part_2(self, result: &mut PartResult, input: &str) -> Result<(), fmt::Error> {
-        match self {
-            #[cfg(feature = "day01")]
-            Day::Day01 => Self::to_string(result, day01::part_2(input)),
-            #[cfg(feature = "day02")]
-            Day::Day02 => Self::to_string(result, day02::part_2(input)),
-            #[cfg(feature = "day03")]
-            Day::Day03 => Self::to_string(result, day03::part_2(input)),
-            #[cfg(feature = "day04")]
-            Day::Day04 => Self::to_string(result, day04::part_2(input)),
-            #[cfg(feature = "day05")]
-            Day::Day05 => Self::to_string(result, day05::part_2(input)),
-            #[cfg(feature = "day06")]
-            Day::Day06 => Self::to_string(result, day06::part_2(input)),
-            #[cfg(feature = "day07")]
-            Day::Day07 => Self::to_string(result, day07::part_2(input)),
-            #[cfg(feature = "day08")]
-            Day::Day08 => Self::to_string(result, day08::part_2(input)),
-            #[cfg(feature = "day09")]
-            Day::Day09 => Self::to_string(result, day09::part_2(input)),
-            #[cfg(all(feature = "day10", feature = "f32"))]
-            Day::Day10 => Self::to_string(result, day10::part_2::<f32>(input)),
-            #[cfg(all(feature = "day10", feature = "f64"))]
-            Day::Day10 => Self::to_string(result, day10::part_2::<f64>(input)),
-            #[cfg(feature = "day11")]
-            Day::Day11 => Self::to_string(result, day11::part_2(input)),
-            #[cfg(feature = "day12")]
-            Day::Day12 => Self::to_string(result, "NO PART 2"),
-        }
-    }
+    Day12 => day12::Day12,
 }
 
 impl core::str::FromStr for Day {
     type Err = &'static str;
 
+    /// Parse a `XY` day of [`DEFAULT_YEAR`] or a year-qualified `YYYY-XY` day.
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        match input.chars().take(2).try_fold(0, |acc, digit| match digit {
-            '0'..='9' => Some(acc * 10 + digit as u32 - '0' as u32),
-            _ => None,
-        }) {
-            #[cfg(feature = "day01")]
-            Some(1) => Ok(Day::Day01),
-            #[cfg(feature = "day02")]
-            Some(2) => Ok(Day::Day02),
-            #[cfg(feature = "day03")]
-            Some(3) => Ok(Day::Day03),
-            #[cfg(feature = "day04")]
-            Some(4) => Ok(Day::Day04),
-            #[cfg(feature = "day05")]
-            Some(5) => Ok(Day::Day05),
-            #[cfg(feature = "day06")]
-            Some(6) => Ok(Day::Day06),
-            #[cfg(feature = "day07")]
-            Some(7) => Ok(Day::Day07),
-            #[cfg(feature = "day08")]
-            Some(8) => Ok(Day::Day08),
-            #[cfg(feature = "day09")]
-            Some(9) => Ok(Day::Day09),
-            #[cfg(all(feature = "day10", any(feature = "f32", feature = "f64")))]
-            Some(10) => Ok(Day::Day10),
-            #[cfg(feature = "day11")]
-            Some(11) => Ok(Day::Day11),
-            #[cfg(feature = "day12")]
-            Some(12) => Ok(Day::Day12),
-            Some(_) => Err("invalid day"),
-            None => Err("invalid number"),
-        }
+        let input = input.trim();
+        let (year, day) = match input.split_once('-') {
+            Some((year, day)) => (year.parse::<u16>().map_err(|_| "invalid year")?, day),
+            None => (DEFAULT_YEAR, input),
+        };
+
+        let day = day
+            .chars()
+            .take(2)
+            .try_fold(0, |acc, digit| Some(acc * 10 + digit.to_digit(10)?))
+            .and_then(|day| u8::try_from(day).ok())
+            .ok_or("invalid number")?;
+
+        Day::ALL
+            .iter()
+            .copied()
+            .find(|d| d.id() == (year, day))
+            .ok_or("invalid day")
     }
 }
 
 #[cfg(any(feature = "blocking", feature = "nonblocking", feature = "minimal"))]
 impl fmt::Display for Day {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        let day = match self {
-            #[cfg(feature = "day01")]
-            Day::Day01 => 1,
-            #[cfg(feature = "day02")]
-            Day::Day02 => 2,
-            #[cfg(feature = "day03")]
-            Day::Day03 => 3,
-            #[cfg(feature = "day04")]
-            Day::Day04 => 4,
-            #[cfg(feature = "day05")]
-            Day::Day05 => 5,
-            #[cfg(feature = "day06")]
-            Day::Day06 => 6,
-            #[cfg(feature = "day07")]
-            Day::Day07 => 7,
-            #[cfg(feature = "day08")]
-            Day::Day08 => 8,
-            #[cfg(feature = "day09")]
-            Day::Day09 => 9,
-            #[cfg(all(feature = "day10", any(feature = "f32", feature = "f64")))]
-            Day::Day10 => 10,
-            #[cfg(feature = "day11")]
-            Day::Day11 => 11,
-            #[cfg(feature = "day12")]
-            Day::Day12 => 12,
-        };
-
-        write!(f, "{day:02}")
+        // as in the header, the days of the workspace event without their year
+        match self.id() {
+            (DEFAULT_YEAR, day) => write!(f, "{day:02}"),
+            (year, day) => write!(f, "{year}-{day:02}"),
+        }
     }
 }
 
@@ -4,7 +4,7 @@ use embedded_io::Read;
 
 use crate::{
     BUFFER_SIZE, Day, Duration, END_INPUT_TAG, Handler, Instant, PartResult, START_INPUT_TAG,
-    Timer, check_eof,
+    Timer, check_eof, split_header,
 };
 
 /// # Panics
@@ -42,16 +42,16 @@ where
                             if let Some(start_position) = input.find(START_INPUT_TAG)
                                 && let Some(end_position) = input.find(END_INPUT_TAG)
                             {
-                                let Ok(day) =
-                                    input[start_position + START_INPUT_TAG.len()..].parse::<Day>()
-                                else {
+                                let (header, data) = split_header(
+                                    &input[start_position + START_INPUT_TAG.len()..end_position],
+                                );
+
+                                let Ok(day) = header.parse::<Day>() else {
                                     handler.unsupported_day();
                                     break;
                                 };
 
-                                let input = input
-                                    [start_position + START_INPUT_TAG.len() + 2..end_position]
-                                    .trim();
+                                let input = data.trim();
 
                                 let mut part_1 = PartResult::new();
                                 let mut part_2 = PartResult::new();
@@ -7,7 +7,7 @@ type Response = heapless::String<255>;
 
 use crate::{
     BUFFER_SIZE, Day, Duration, END_INPUT_TAG, Handler, Instant, PartResult, START_INPUT_TAG,
-    Timer, check_eof, info, trace, warn,
+    Timer, check_eof, info, split_header, trace, warn,
 };
 
 /// # Panics
@@ -55,9 +55,11 @@ where
                             if let Some(start_position) = input.find(START_INPUT_TAG)
                                 && let Some(end_position) = input.find(END_INPUT_TAG)
                             {
-                                let Ok(day) =
-                                    input[start_position + START_INPUT_TAG.len()..].parse::<Day>()
-                                else {
+                                let (header, data) = split_header(
+                                    &input[start_position + START_INPUT_TAG.len()..end_position],
+                                );
+
+                                let Ok(day) = header.parse::<Day>() else {
                                     warn!("unsupported day");
 
                                     handler.unsupported_day();
@@ -67,9 +69,7 @@ where
                                     break;
                                 };
 
-                                let input = input
-                                    [start_position + START_INPUT_TAG.len() + 2..end_position]
-                                    .trim();
+                                let input = data.trim();
 
                                 info!("[{}] start working on {}", day, day);
 
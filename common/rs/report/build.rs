@@ -0,0 +1,6 @@
+fn main() {
+    println!(
+        "cargo::rustc-env=REPORT_TARGET={}",
+        std::env::var("TARGET").expect("cannot find TARGET")
+    );
+}
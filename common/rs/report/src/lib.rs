@@ -0,0 +1,541 @@
+use std::env;
+use std::io::{self, Write};
+use std::process;
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use sha2::{Digest, Sha256};
+
+use solution::Solution;
+
+mod timing;
+pub use timing::{Budget, DEFAULT_WARMUP, MAX_RUNS, Repeat, Stats, parse_time};
+
+mod traced;
+pub use traced::{SpanTotal, traced};
+
+/// Target triple the binary was built for.
+pub const TARGET: &str = env!("REPORT_TARGET");
+
+/// Output format of the day binaries, selected with `--format`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Answers and total time for humans.
+    #[default]
+    Text,
+    Json,
+    /// Header and a single row.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("invalid format {s}, expected text, json or csv")),
+        }
+    }
+}
+
+/// Usage of the command line of the day binaries.
+pub const USAGE: &str = "usage: [--format text|json|csv] [--repeat <N|TIME>] [--warmup <N>]";
+
+/// Command line of the day binaries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Options {
+    pub format: Format,
+    pub repeat: Repeat,
+}
+
+impl Options {
+    /// Options of the command line arguments, without the program name, as `--name <value>`
+    /// or `--name=<value>`: `--format`, `--repeat` with an iteration count like `100` or a
+    /// time like `2s`, and `--warmup`, [`DEFAULT_WARMUP`] when only `--repeat` is given.
+    ///
+    /// # Errors
+    /// When an argument is unknown or a value is invalid.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Options::default();
+        let mut warmup = None;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (name, value) = if let Some((name, value)) = arg.split_once('=') {
+                (name.to_string(), value.to_string())
+            } else {
+                let value = args.next().ok_or(format!("{arg} needs a value"))?;
+                (arg, value)
+            };
+            match name.as_str() {
+                "--format" => options.format = value.parse()?,
+                "--repeat" => options.repeat.budget = value.parse()?,
+                "--warmup" => {
+                    warmup = Some(
+                        value
+                            .parse()
+                            .map_err(|_| format!("invalid warmup {value}"))?,
+                    );
+                }
+                _ => return Err(format!("unexpected argument {name}")),
+            }
+        }
+
+        options.repeat.warmup = warmup.unwrap_or(if options.repeat == Repeat::default() {
+            0
+        } else {
+            DEFAULT_WARMUP
+        });
+
+        Ok(options)
+    }
+}
+
+/// Answer and running time of a part, the median with statistics when repeated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Part {
+    pub answer: String,
+    #[serde(rename = "elapsed_ns", serialize_with = "serialize_nanos")]
+    pub elapsed: Duration,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<Stats>,
+    /// Spans of the part, see [`traced`].
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub spans: Vec<SpanTotal>,
+}
+
+impl Part {
+    /// Part timed by [`Repeat::measure`], keeping the statistics of several runs.
+    #[must_use]
+    pub fn new(answer: String, stats: Stats, spans: Vec<SpanTotal>) -> Self {
+        Self {
+            answer,
+            elapsed: stats.median,
+            stats: (stats.runs > 1).then_some(stats),
+            spans,
+        }
+    }
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+pub(crate) fn serialize_nanos<S: serde::Serializer>(
+    elapsed: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(nanos(*elapsed))
+}
+
+pub(crate) fn deserialize_nanos<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Duration, D::Error> {
+    u64::deserialize(deserializer).map(Duration::from_nanos)
+}
+
+fn nanos(elapsed: Duration) -> u64 {
+    elapsed.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// Run of a day on an input, with what it was built with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub part_1: Part,
+    pub part_2: Option<Part>,
+    /// Lowercase hex SHA-256 of the input.
+    pub input_sha256: String,
+    /// Enabled features of the day crate, see [`features!`].
+    pub features: Vec<&'static str>,
+    pub target: &'static str,
+}
+
+impl Report {
+    /// Solve both parts of `input`, timing each one according to `repeat`.
+    #[must_use]
+    pub fn new<S: Solution>(input: &str, features: Vec<&'static str>, repeat: &Repeat) -> Self {
+        let ((answer, stats), spans) = traced(|| repeat.measure(|| S::part_1(input)));
+        let part_1 = Part::new(answer.to_string(), stats, spans);
+
+        let ((answer, stats), spans) = traced(|| repeat.measure(|| S::part_2(input)));
+        let part_2 = answer.map(|answer| Part::new(answer.to_string(), stats, spans));
+
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            part_1,
+            part_2,
+            input_sha256: hex::encode(Sha256::digest(input)),
+            features,
+            target: TARGET,
+        }
+    }
+
+    /// Parts with their number.
+    pub fn parts(&self) -> impl Iterator<Item = (u32, &Part)> {
+        [(1, Some(&self.part_1)), (2, self.part_2.as_ref())]
+            .into_iter()
+            .filter_map(|(part, outcome)| Some((part, outcome?)))
+    }
+
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.part_1.elapsed
+            + self
+                .part_2
+                .as_ref()
+                .map_or(Duration::ZERO, |part| part.elapsed)
+    }
+
+    /// # Errors
+    /// When `out` cannot be written.
+    pub fn write(&self, format: Format, mut out: impl Write) -> io::Result<()> {
+        match format {
+            Format::Text => {
+                writeln!(out, "part 1: {}", self.part_1.answer)?;
+                if let Some(part_2) = &self.part_2 {
+                    writeln!(out, "part 2: {}", part_2.answer)?;
+                }
+
+                if self.part_1.stats.is_none() {
+                    let elapsed = self.elapsed();
+                    writeln!(
+                        out,
+                        "elapsed: {}ms ({}µs, {}ns)",
+                        elapsed.as_millis(),
+                        elapsed.as_micros(),
+                        elapsed.as_nanos()
+                    )?;
+                } else {
+                    writeln!(out, "{:<6} {}", "part", Stats::header())?;
+                    for (part, outcome) in self.parts() {
+                        if let Some(stats) = &outcome.stats {
+                            writeln!(out, "{part:<6} {stats}")?;
+                        }
+                    }
+                }
+
+                if self.parts().any(|(_, outcome)| !outcome.spans.is_empty()) {
+                    writeln!(out, "{:<6} {}", "part", SpanTotal::header())?;
+                    for (part, outcome) in self.parts() {
+                        for span in &outcome.spans {
+                            writeln!(out, "{part:<6} {span}")?;
+                        }
+                    }
+                }
+                Ok(())
+            }
+            Format::Json => {
+                serde_json::to_writer(&mut out, self)?;
+                writeln!(out)
+            }
+            Format::Csv => {
+                write!(
+                    out,
+                    "year,day,part_1,part_1_ns,part_2,part_2_ns,input_sha256,features,target"
+                )?;
+                for part in 1..=2 {
+                    for stat in ["runs", "min_ns", "median_ns", "mean_ns", "p95_ns"] {
+                        write!(out, ",part_{part}_{stat}")?;
+                    }
+                }
+                write!(out, ",part_1_spans,part_2_spans")?;
+                writeln!(out)?;
+
+                let (answer_2, elapsed_2) = self
+                    .part_2
+                    .as_ref()
+                    .map_or((String::new(), String::new()), |part| {
+                        (csv_field(&part.answer), nanos(part.elapsed).to_string())
+                    });
+                write!(
+                    out,
+                    "{},{},{},{},{answer_2},{elapsed_2},{},{},{}",
+                    self.year,
+                    self.day,
+                    csv_field(&self.part_1.answer),
+                    nanos(self.part_1.elapsed),
+                    self.input_sha256,
+                    csv_field(&self.features.join(";")),
+                    self.target,
+                )?;
+                for part in [Some(&self.part_1), self.part_2.as_ref()] {
+                    match part.and_then(|part| part.stats.as_ref()) {
+                        Some(stats) => write!(
+                            out,
+                            ",{},{},{},{},{}",
+                            stats.runs,
+                            nanos(stats.min),
+                            nanos(stats.median),
+                            nanos(stats.mean),
+                            nanos(stats.p95)
+                        )?,
+                        None => write!(out, ",,,,,")?,
+                    }
+                }
+                for part in [Some(&self.part_1), self.part_2.as_ref()] {
+                    let spans = part.map_or(String::new(), |part| {
+                        part.spans
+                            .iter()
+                            .map(|span| format!("{}:{}", span.name, nanos(span.total)))
+                            .collect::<Vec<_>>()
+                            .join(";")
+                    });
+                    write!(out, ",{}", csv_field(&spans))?;
+                }
+                writeln!(out)
+            }
+        }
+    }
+}
+
+/// Quote `field` when it contains a separator, a quote or a line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Features among the given ones enabled in the calling crate, followed by the names
+/// after `;` of features picked by the crate itself, e.g. the float of a solver.
+#[macro_export]
+macro_rules! features {
+    ($($feature:literal),* $(,)?) => {
+        $crate::features!($($feature),*;)
+    };
+    ($($feature:literal),* ; $($picked:expr),* $(,)?) => {
+        <[(&'static str, bool)]>::iter(&[$(($feature, cfg!(feature = $feature))),*])
+            .filter_map(|&(feature, enabled)| enabled.then_some(feature))
+            .chain([$($picked),*])
+            .collect::<Vec<&'static str>>()
+    };
+}
+
+/// Main of the day binaries: solve `input` and print the report as set by the command
+/// line, see [`Options::from_args`], exiting with status 2 on invalid arguments.
+///
+/// # Panics
+/// When stdout cannot be written.
+pub fn run<S: Solution>(input: &str, features: Vec<&'static str>) {
+    let options = Options::from_args(env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("error: {err}\n{USAGE}");
+        process::exit(2);
+    });
+
+    Report::new::<S>(input, features, &options.repeat)
+        .write(options.format, io::stdout().lock())
+        .expect("cannot write report");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2025;
+        const DAY: u8 = 1;
+
+        type Part1 = u32;
+        type Part2 = String;
+
+        fn part_1(input: &str) -> Self::Part1 {
+            input.split(',').map(|n| n.parse::<u32>().unwrap()).sum()
+        }
+
+        fn part_2(input: &str) -> Option<Self::Part2> {
+            Some(format!("{input},\"x\""))
+        }
+    }
+
+    fn output(report: &Report, format: Format) -> String {
+        let mut out = vec![];
+        report.write(format, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_options() {
+        let args = |args: &[&str]| Options::from_args(args.iter().map(ToString::to_string));
+
+        assert_eq!(args(&[]).unwrap(), Options::default());
+        assert_eq!(args(&["--format", "json"]).unwrap().format, Format::Json);
+        assert_eq!(args(&["--format=csv"]).unwrap().format, Format::Csv);
+        assert!(args(&["--format"]).is_err());
+        assert!(args(&["--format", "xml"]).is_err());
+        assert!(args(&["--json"]).is_err());
+
+        assert_eq!(
+            args(&["--repeat", "2s"]).unwrap().repeat,
+            Repeat {
+                warmup: DEFAULT_WARMUP,
+                budget: Budget::Time(Duration::from_secs(2))
+            }
+        );
+        assert_eq!(
+            args(&["--warmup=0", "--repeat=10"]).unwrap().repeat,
+            Repeat {
+                warmup: 0,
+                budget: Budget::Iterations(10)
+            }
+        );
+        assert!(args(&["--warmup", "x"]).is_err());
+    }
+
+    #[test]
+    fn test_features() {
+        let tracing = cfg!(feature = "tracing").then_some("tracing");
+
+        assert_eq!(
+            features!("tracing"),
+            tracing.into_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            features!("tracing"; "f64"),
+            tracing.into_iter().chain(["f64"]).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let mut report = Report::new::<Sum>("1,2", vec!["rayon"], &Repeat::default());
+        report.part_1.elapsed = Duration::from_micros(1500);
+        report.part_2.as_mut().unwrap().elapsed = Duration::from_nanos(7);
+
+        assert_eq!(report.part_1.answer, "3");
+        assert_eq!(report.target, TARGET);
+        assert_eq!(
+            report.input_sha256,
+            "17f8af97ad4a7f7639a4c9171d5185cbafb85462877a4746c21bdb0a4f940ca0"
+        );
+
+        assert_eq!(
+            output(&report, Format::Text),
+            "part 1: 3\npart 2: 1,2,\"x\"\nelapsed: 1ms (1500µs, 1500007ns)\n"
+        );
+
+        let json =
+            serde_json::from_str::<serde_json::Value>(&output(&report, Format::Json)).unwrap();
+        assert_eq!(json["day"], 1);
+        assert_eq!(json["part_1"]["answer"], "3");
+        assert_eq!(json["part_1"]["elapsed_ns"], 1_500_000);
+        assert_eq!(json["part_2"]["elapsed_ns"], 7);
+        assert_eq!(json["features"][0], "rayon");
+
+        let csv = output(&report, Format::Csv);
+        let mut lines = csv.lines();
+        assert!(
+            lines
+                .next()
+                .unwrap()
+                .starts_with("year,day,part_1,part_1_ns,part_2,")
+        );
+        assert_eq!(
+            lines.next().unwrap(),
+            format!(
+                "2025,1,3,1500000,\"1,2,\"\"x\"\"\",7,{},rayon,{TARGET},,,,,,,,,,,,",
+                report.input_sha256
+            )
+        );
+    }
+
+    #[test]
+    fn test_repeated_report() {
+        let repeat = Repeat {
+            warmup: 1,
+            budget: Budget::Iterations(4),
+        };
+        let report = Report::new::<Sum>("1,2", vec![], &repeat);
+
+        let stats = report.part_1.stats.unwrap();
+        assert_eq!(stats.runs, 4);
+        assert_eq!(report.part_1.elapsed, stats.median);
+        assert!(stats.min <= stats.median && stats.median <= stats.p95);
+
+        let text = output(&report, Format::Text);
+        assert!(text.starts_with("part 1: 3\npart 2: 1,2,\"x\"\npart       runs"));
+        assert!(text.lines().nth(3).unwrap().starts_with("1             4 "));
+        assert!(text.lines().nth(4).unwrap().starts_with("2             4 "));
+
+        let json =
+            serde_json::from_str::<serde_json::Value>(&output(&report, Format::Json)).unwrap();
+        assert_eq!(json["part_2"]["stats"]["runs"], 4);
+        assert!(json["part_2"]["stats"]["p95_ns"].is_u64());
+
+        let csv = output(&report, Format::Csv);
+        let mut lines = csv.lines();
+        assert!(
+            lines
+                .next()
+                .unwrap()
+                .ends_with(",part_2_p95_ns,part_1_spans,part_2_spans")
+        );
+        let row = lines.next().unwrap().split(',').collect::<Vec<_>>();
+        assert_eq!(row.len(), 23);
+        assert_eq!((row[11], row[16]), ("4", "4"));
+    }
+
+    #[test]
+    fn test_spans() {
+        let mut report = Report::new::<Sum>("1,2", vec![], &Repeat::default());
+        report.part_1.elapsed = Duration::from_micros(3);
+        report.part_2.as_mut().unwrap().elapsed = Duration::ZERO;
+        report.part_1.spans = vec![
+            SpanTotal {
+                name: "parse".into(),
+                calls: 2,
+                total: Duration::from_micros(2),
+            },
+            SpanTotal {
+                name: "solve".into(),
+                calls: 1,
+                total: Duration::from_micros(1),
+            },
+        ];
+        assert_eq!(report.part_1.spans[0].per_call(), Duration::from_micros(1));
+
+        let text = output(&report, Format::Text);
+        let mut lines = text.lines().skip(3);
+        assert!(
+            lines
+                .next()
+                .unwrap()
+                .starts_with("part   span            calls")
+        );
+        assert_eq!(
+            lines.next().unwrap(),
+            "1      parse               2      2.000µs      1.000µs"
+        );
+        assert!(lines.next().unwrap().starts_with("1      solve "));
+        assert_eq!(lines.next(), None);
+
+        let json =
+            serde_json::from_str::<serde_json::Value>(&output(&report, Format::Json)).unwrap();
+        assert_eq!(json["part_1"]["spans"][0]["name"], "parse");
+        assert_eq!(json["part_1"]["spans"][0]["total_ns"], 2000);
+        assert!(json["part_2"].get("spans").is_none());
+
+        let csv = output(&report, Format::Csv);
+        let row = csv.lines().nth(1).unwrap();
+        assert!(row.ends_with(",parse:2000;solve:1000,"));
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_traced() {
+        let (answer, spans) = traced(|| {
+            spans::scope("parse", || ());
+            spans::scope("parse", || 42)
+        });
+        assert_eq!(answer, 42);
+        assert_eq!(spans.len(), 1);
+        assert_eq!((&*spans[0].name, spans[0].calls), ("parse", 2));
+    }
+}
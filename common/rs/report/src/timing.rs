@@ -0,0 +1,272 @@
+use std::fmt;
+use std::hint;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+/// Warmup runs used by `--repeat` unless `--warmup` is given.
+pub const DEFAULT_WARMUP: u32 = 3;
+
+/// Upper bound of the timed runs of a [`Budget::Time`], so fast parts do not pile up
+/// samples.
+pub const MAX_RUNS: u32 = 100_000;
+
+/// How long to keep timing a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Budget {
+    Iterations(u32),
+    /// Run until the timed runs add up to the duration, at least once.
+    Time(Duration),
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Budget::Iterations(1)
+    }
+}
+
+/// Iteration count like `100` or duration in milliseconds like `500ms`, parsed back by
+/// [`FromStr`].
+impl fmt::Display for Budget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Budget::Iterations(iterations) => write!(f, "{iterations}"),
+            Budget::Time(time) => write!(f, "{}ms", time.as_secs_f64() * 1e3),
+        }
+    }
+}
+
+/// Parse an iteration count like `100` or a duration like `500ms` or `2s`.
+impl FromStr for Budget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            format!("invalid budget {s}, expected iterations like 100 or a time like 500ms or 2s")
+        };
+
+        if let Ok(iterations) = s.parse::<u32>() {
+            return if iterations == 0 {
+                Err(invalid())
+            } else {
+                Ok(Budget::Iterations(iterations))
+            };
+        }
+
+        parse_time(s).map(Budget::Time).map_err(|_| invalid())
+    }
+}
+
+/// Parse a positive time like `500ms` or `2s`.
+///
+/// # Errors
+/// When `s` is not a positive number of milliseconds or seconds.
+pub fn parse_time(s: &str) -> Result<Duration, String> {
+    let invalid = || format!("invalid time {s}, expected a time like 500ms or 2s");
+
+    let (value, unit) = if let Some(value) = s.strip_suffix("ms") {
+        (value, 1e-3)
+    } else if let Some(value) = s.strip_suffix('s') {
+        (value, 1.)
+    } else {
+        return Err(invalid());
+    };
+    value
+        .parse::<f64>()
+        .ok()
+        .and_then(|value| Duration::try_from_secs_f64(value * unit).ok())
+        .filter(|time| !time.is_zero())
+        .ok_or_else(invalid)
+}
+
+/// Untimed warmup runs followed by timed runs within a budget; the default is a
+/// single cold run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Repeat {
+    pub warmup: u32,
+    pub budget: Budget,
+}
+
+impl Repeat {
+    /// Time `part` according to the repeat settings, returning the answer of the last
+    /// run with its timing statistics.
+    pub fn measure<T>(&self, mut part: impl FnMut() -> T) -> (T, Stats) {
+        for _ in 0..self.warmup {
+            hint::black_box(part());
+        }
+
+        let mut samples = vec![];
+        let mut total = Duration::ZERO;
+        loop {
+            let now = Instant::now();
+            let answer = hint::black_box(part());
+            let elapsed = now.elapsed();
+
+            samples.push(elapsed);
+            total += elapsed;
+
+            let runs = u32::try_from(samples.len()).unwrap_or(u32::MAX);
+            let done = match self.budget {
+                Budget::Iterations(iterations) => runs >= iterations,
+                Budget::Time(budget) => total >= budget || runs >= MAX_RUNS,
+            };
+            if done {
+                return (answer, Stats::new(&mut samples));
+            }
+        }
+    }
+}
+
+/// Summary of the timed runs of a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: u32,
+    #[serde(
+        rename = "min_ns",
+        serialize_with = "crate::serialize_nanos",
+        deserialize_with = "crate::deserialize_nanos"
+    )]
+    pub min: Duration,
+    #[serde(
+        rename = "median_ns",
+        serialize_with = "crate::serialize_nanos",
+        deserialize_with = "crate::deserialize_nanos"
+    )]
+    pub median: Duration,
+    #[serde(
+        rename = "mean_ns",
+        serialize_with = "crate::serialize_nanos",
+        deserialize_with = "crate::deserialize_nanos"
+    )]
+    pub mean: Duration,
+    /// 95th percentile, nearest rank.
+    #[serde(
+        rename = "p95_ns",
+        serialize_with = "crate::serialize_nanos",
+        deserialize_with = "crate::deserialize_nanos"
+    )]
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// # Panics
+    /// When `samples` is empty.
+    #[must_use]
+    pub fn new(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+
+        samples.sort_unstable();
+
+        let len = samples.len();
+        let runs = u32::try_from(len).expect("too many samples");
+
+        let median = if len.is_multiple_of(2) {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        } else {
+            samples[len / 2]
+        };
+
+        Self {
+            runs,
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / runs,
+            p95: samples[(len * 95).div_ceil(100) - 1],
+        }
+    }
+
+    /// Header of the columns of the [`fmt::Display`] of the statistics.
+    #[must_use]
+    pub fn header() -> String {
+        format!(
+            "{:>8} {:>12} {:>12} {:>12} {:>12}",
+            "runs", "min", "median", "mean", "p95"
+        )
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:>8} {:>12} {:>12} {:>12} {:>12}",
+            self.runs,
+            format!("{:.3?}", self.min),
+            format!("{:.3?}", self.median),
+            format!("{:.3?}", self.mean),
+            format!("{:.3?}", self.p95),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budget() {
+        assert_eq!("100".parse(), Ok(Budget::Iterations(100)));
+        assert_eq!(
+            "500ms".parse(),
+            Ok(Budget::Time(Duration::from_millis(500)))
+        );
+        assert_eq!(
+            "1.5s".parse(),
+            Ok(Budget::Time(Duration::from_millis(1500)))
+        );
+        assert!("0".parse::<Budget>().is_err());
+        assert!("0s".parse::<Budget>().is_err());
+        assert!("2m".parse::<Budget>().is_err());
+        assert!("-1s".parse::<Budget>().is_err());
+
+        assert_eq!(parse_time("250ms"), Ok(Duration::from_millis(250)));
+
+        for budget in [
+            Budget::Iterations(100),
+            Budget::Time(Duration::from_millis(1500)),
+        ] {
+            assert_eq!(budget.to_string().parse(), Ok(budget));
+        }
+        assert!(parse_time("10").is_err());
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut samples = (1..=20)
+            .rev()
+            .map(Duration::from_micros)
+            .collect::<Vec<_>>();
+        let stats = Stats::new(&mut samples);
+        assert_eq!(stats.runs, 20);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(10_500));
+        assert_eq!(stats.mean, Duration::from_nanos(10_500));
+        assert_eq!(stats.p95, Duration::from_micros(19));
+
+        let stats = Stats::new(&mut [Duration::from_millis(3)]);
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut calls = 0;
+        let repeat = Repeat {
+            warmup: 2,
+            budget: Budget::Iterations(5),
+        };
+        let (answer, stats) = repeat.measure(|| {
+            calls += 1;
+            calls
+        });
+        assert_eq!((answer, calls, stats.runs), (7, 7, 5));
+
+        let repeat = Repeat {
+            warmup: 0,
+            budget: Budget::Time(Duration::from_millis(1)),
+        };
+        let ((), stats) = repeat.measure(|| std::thread::sleep(Duration::from_micros(100)));
+        assert!((1..=10).contains(&stats.runs));
+    }
+}
@@ -0,0 +1,74 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Time spent in the spans of a name inside a part, with the `tracing` feature.
+///
+/// The totals add up every run of the part, warmup runs included: the time per call is
+/// what compares across runs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpanTotal {
+    pub name: Cow<'static, str>,
+    pub calls: u64,
+    #[serde(
+        rename = "total_ns",
+        serialize_with = "crate::serialize_nanos",
+        deserialize_with = "crate::deserialize_nanos"
+    )]
+    pub total: Duration,
+}
+
+impl SpanTotal {
+    #[must_use]
+    pub fn per_call(&self) -> Duration {
+        Duration::from_nanos(crate::nanos(self.total) / self.calls.max(1))
+    }
+
+    /// Header of the columns of the [`fmt::Display`] of the totals.
+    #[must_use]
+    pub fn header() -> String {
+        format!(
+            "{:<12} {:>8} {:>12} {:>12}",
+            "span", "calls", "total", "per call"
+        )
+    }
+}
+
+impl fmt::Display for SpanTotal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:<12} {:>8} {:>12} {:>12}",
+            self.name,
+            self.calls,
+            format!("{:.3?}", self.total),
+            format!("{:.3?}", self.per_call()),
+        )
+    }
+}
+
+/// Run `f` collecting the spans it ends, on every thread; no spans without the `tracing`
+/// feature.
+///
+/// The collector is global: spans of anything running alongside `f` are counted too.
+pub fn traced<T>(f: impl FnOnce() -> T) -> (T, Vec<SpanTotal>) {
+    #[cfg(feature = "tracing")]
+    {
+        spans::take();
+        let value = f();
+        let totals = spans::take()
+            .into_iter()
+            .map(|total| SpanTotal {
+                name: total.name.into(),
+                calls: total.calls,
+                total: total.elapsed,
+            })
+            .collect();
+        (value, totals)
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    (f(), vec![])
+}
@@ -58,6 +58,8 @@ where
     Self: ops::DivAssign + ops::SubAssign,
     Self: ops::Neg<Output = Self>,
 {
+    /// Name of the float, as its cargo feature in the day crates.
+    const NAME: &'static str;
     const EPS: Self;
     const MAX: Self;
     const MIN: Self;
@@ -89,6 +91,7 @@ where
 macro_rules! impl_float {
     ($t:tt, $eps:literal) => {
         impl Float for $t {
+            const NAME: &'static str = stringify!($t);
             const EPS: Self = $eps;
             const MAX: Self = <$t>::MAX;
             const MIN: Self = <$t>::MIN;
@@ -0,0 +1,23 @@
+#![no_std]
+
+use core::fmt::Display;
+
+/// Puzzle of a day: its event and day, and the solvers of both parts.
+///
+/// Implemented by a marker type of every day crate, e.g. `day01::Day01`, with the
+/// variants of parameterized solvers as type parameters, e.g. `day10::Day10<f64>`.
+pub trait Solution {
+    const YEAR: u16;
+
+    const DAY: u8;
+
+    type Part1: Display;
+
+    /// [`core::convert::Infallible`] for days without a second part.
+    type Part2: Display;
+
+    fn part_1(input: &str) -> Self::Part1;
+
+    /// `None` for days without a second part, like the last day of an event.
+    fn part_2(input: &str) -> Option<Self::Part2>;
+}
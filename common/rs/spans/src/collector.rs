@@ -0,0 +1,77 @@
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+use std::vec::Vec;
+
+static TOTALS: Mutex<Vec<Total>> = Mutex::new(Vec::new());
+
+/// Time spent in the spans of a name, on every thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Total {
+    pub name: &'static str,
+    pub calls: u64,
+    pub elapsed: Duration,
+}
+
+pub(crate) fn record(name: &'static str, elapsed: Duration) {
+    let mut totals = TOTALS.lock().unwrap_or_else(PoisonError::into_inner);
+    match totals.iter_mut().find(|total| total.name == name) {
+        Some(total) => {
+            total.calls += 1;
+            total.elapsed += elapsed;
+        }
+        None => totals.push(Total {
+            name,
+            calls: 1,
+            elapsed,
+        }),
+    }
+}
+
+/// Totals of the spans ended since the last call, in order of first end.
+pub fn take() -> Vec<Total> {
+    core::mem::take(&mut *TOTALS.lock().unwrap_or_else(PoisonError::into_inner))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{enter, scope};
+
+    #[test]
+    fn test_take() {
+        take();
+
+        let answer = scope("parse", || {
+            let _span = enter("inner");
+            42
+        });
+        assert_eq!(answer, 42);
+        scope("parse", || {});
+        drop(enter("solve"));
+
+        let totals = take();
+        assert_eq!(
+            totals
+                .iter()
+                .map(|total| (total.name, total.calls))
+                .collect::<Vec<_>>(),
+            [("inner", 1), ("parse", 2), ("solve", 1)]
+        );
+        assert!(totals[1].elapsed >= totals[0].elapsed);
+        assert!(take().is_empty());
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| drop(enter("thread")));
+            }
+        });
+        assert_eq!(
+            take()
+                .iter()
+                .map(|total| (total.name, total.calls))
+                .collect::<Vec<_>>(),
+            [("thread", 4)]
+        );
+    }
+}
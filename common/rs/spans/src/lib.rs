@@ -0,0 +1,67 @@
+#![no_std]
+
+//! Named spans inside the parts of the days, to tell parsing from solving.
+//!
+//! Spans cost nothing unless a backend is enabled:
+//! - `std` adds up the time of every span by name, see [`take`]
+//! - `defmt` and `log` log the entry and the exit of every span, the timestamps of the
+//!   logger giving the time spent in between
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+mod collector;
+#[cfg(feature = "std")]
+pub use collector::{Total, take};
+
+/// Span open until dropped.
+#[must_use = "the span ends when dropped"]
+pub struct Span {
+    #[cfg(any(feature = "std", feature = "defmt", feature = "log"))]
+    name: &'static str,
+    #[cfg(feature = "std")]
+    start: std::time::Instant,
+}
+
+/// Open the span `name`.
+#[inline]
+#[cfg_attr(
+    not(any(feature = "std", feature = "defmt", feature = "log")),
+    allow(unused_variables)
+)]
+pub fn enter(name: &'static str) -> Span {
+    #[cfg(feature = "defmt")]
+    defmt::info!("enter {=str}", name);
+
+    #[cfg(feature = "log")]
+    log::info!("enter {name}");
+
+    Span {
+        #[cfg(any(feature = "std", feature = "defmt", feature = "log"))]
+        name,
+        #[cfg(feature = "std")]
+        start: std::time::Instant::now(),
+    }
+}
+
+/// Run `f` in the span `name`.
+#[inline]
+pub fn scope<T>(name: &'static str, f: impl FnOnce() -> T) -> T {
+    let _span = enter(name);
+    f()
+}
+
+impl Drop for Span {
+    #[inline]
+    fn drop(&mut self) {
+        #[cfg(feature = "std")]
+        collector::record(self.name, self.start.elapsed());
+
+        #[cfg(feature = "defmt")]
+        defmt::info!("exit {=str}", self.name);
+
+        #[cfg(feature = "log")]
+        log::info!("exit {}", self.name);
+    }
+}
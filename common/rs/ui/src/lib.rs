@@ -1,5 +1,4 @@
 use std::marker::PhantomData;
-use std::fmt::Display;
 use std::time::Duration;
 
 use web_sys::HtmlInputElement;
@@ -8,33 +7,26 @@ use instant::Instant;
 
 use yew::prelude::*;
 
-#[derive(Properties)]
-pub struct ModelProps<SOLVE1, SOLVE2> {
+use solution::Solution;
+
+#[derive(Properties, PartialEq, Default)]
+pub struct ModelProps {
     pub input: String,
-    pub solve_1: SOLVE1,
-    pub solve_2: SOLVE2,
 }
 
 pub enum Msg {
     Run(String),
 }
 
-pub struct Model<SOLVE1, P1T, SOLVE2, P2T> {
+pub struct Model<S> {
     input_ref: NodeRef,
-    part1: Option<P1T>,
-    part2: Option<P2T>,
+    part1: Option<String>,
+    part2: Option<String>,
     input: String,
     elapsed_part_1: Option<Duration>,
     elapsed_part_2: Option<Duration>,
     elapsed_total: Option<Duration>,
-    _solve1: PhantomData<SOLVE1>,
-    _solve2: PhantomData<SOLVE2>,
-}
-
-impl<SOLVE1, SOLVE2> PartialEq for ModelProps<SOLVE1, SOLVE2> {
-    fn eq(&self, other: &Self) -> bool {
-        self.input.eq(&other.input)
-    }
+    _solution: PhantomData<S>,
 }
 
 fn format_duration(elapsed: Option<Duration>) -> String {
@@ -42,14 +34,11 @@ fn format_duration(elapsed: Option<Duration>) -> String {
         .map_or_else(|| "not run".to_string(), |v| format!("{}ms ({}us)", v.as_millis(), v.as_micros()))
 }
 
-impl<SOLVE1, P1T, SOLVE2, P2T> Component for Model<SOLVE1, P1T, SOLVE2, P2T>
-where SOLVE1: Fn(&str) -> P1T + 'static,
-      SOLVE2: Fn(&str) -> P2T + 'static,
-      P1T: Display + Clone + 'static,
-      P2T: Display + Clone + 'static,
+impl<S> Component for Model<S>
+where S: Solution + 'static,
 {
     type Message = Msg;
-    type Properties = ModelProps<SOLVE1, SOLVE2>;
+    type Properties = ModelProps;
 
     fn create(ctx: &Context<Self>) -> Self {
         let input = ctx.props().input.clone();
@@ -62,19 +51,18 @@ where SOLVE1: Fn(&str) -> P1T + 'static,
             elapsed_part_1: None,
             elapsed_part_2: None,
             elapsed_total: None,
-            _solve1: PhantomData,
-            _solve2: PhantomData,
+            _solution: PhantomData,
         }
     }
 
-    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             Msg::Run(input) => {
                 let now_part_1 = Instant::now();
-                self.part1 = Some((ctx.props().solve_1)(&input));
+                self.part1 = Some(S::part_1(&input).to_string());
                 self.elapsed_part_1 = Some(now_part_1.elapsed());
                 let now_part_2 = Instant::now();
-                self.part2 = Some((ctx.props().solve_2)(&input));
+                self.part2 = Some(S::part_2(&input).map_or_else(|| "-".to_string(), |v| v.to_string()));
                 self.elapsed_part_2 = Some(now_part_2.elapsed());
                 self.elapsed_total = Some(now_part_1.elapsed());
                 self.input = input;
@@ -113,3 +101,10 @@ where SOLVE1: Fn(&str) -> P1T + 'static,
         }
     }
 }
+
+/// Render the page of the day `S` with an empty input.
+pub fn render<S>()
+where S: Solution + 'static,
+{
+    yew::Renderer::<Model<S>>::new().render();
+}
@@ -4,6 +4,8 @@
 #[cfg_attr(target_os = "none", inline(never))]
 #[must_use]
 pub fn part_1(data: &str) -> usize {
+    let _span = spans::enter("solve");
+
     let mut current = 50;
     data.lines()
         .filter(|line| {
@@ -24,6 +26,8 @@ pub fn part_1(data: &str) -> usize {
 #[cfg_attr(target_os = "none", inline(never))]
 #[must_use]
 pub fn part_2(data: &str) -> i64 {
+    let _span = spans::enter("solve");
+
     let mut current = 50;
     data.lines()
         .map(|line| {
@@ -47,6 +51,25 @@ pub fn part_2(data: &str) -> i64 {
         .sum()
 }
 
+/// Day 1 of 2025.
+pub struct Day01;
+
+impl solution::Solution for Day01 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn part_1(input: &str) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(input: &str) -> Option<Self::Part2> {
+        Some(part_2(input))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -179,6 +179,8 @@ fn sum_invalid_ids_m(low: u64, high: u64) -> u64 {
 
 /// # Panics
 fn solve(data: &str, f: impl Fn(u64, u64) -> u64 + Sync + Send) -> u64 {
+    let _span = spans::enter("solve");
+
     #[cfg(feature = "rayon")]
     let i = data.trim().par_split(',');
 
@@ -209,6 +211,25 @@ pub fn part_2(data: &str) -> u64 {
     solve(data, sum_invalid_ids_m)
 }
 
+/// Day 2 of 2025.
+pub struct Day02;
+
+impl solution::Solution for Day02 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn part_1(input: &str) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(input: &str) -> Option<Self::Part2> {
+        Some(part_2(input))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,6 +6,8 @@ use rayon::prelude::*;
 /// # Panics
 #[must_use]
 fn solve<const SIZE: usize>(data: &str) -> u64 {
+    let _span = spans::enter("solve");
+
     #[cfg(feature = "rayon")]
     let i = data.par_lines();
 
@@ -55,6 +57,25 @@ pub fn part_2(data: &str) -> u64 {
     solve::<12>(data)
 }
 
+/// Day 3 of 2025.
+pub struct Day03;
+
+impl solution::Solution for Day03 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn part_1(input: &str) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(input: &str) -> Option<Self::Part2> {
+        Some(part_2(input))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,6 +90,14 @@ mod tests {
         assert_eq!(part_1(INPUT), 357);
     }
 
+    #[test]
+    fn test_part_1_crlf() {
+        assert_eq!(
+            part_1("987654321111111\r\n811111111111119\r\n23423423423427\r\n"),
+            234
+        );
+    }
+
     #[test]
     #[allow(clippy::unreadable_literal)]
     fn test_part_2() {
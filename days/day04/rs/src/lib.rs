@@ -18,12 +18,16 @@ const NEIGHBORS: [(isize, isize); 8] = [
 #[cfg_attr(target_os = "none", inline(never))]
 #[must_use]
 pub fn part_1(data: &str) -> usize {
+    let parse = spans::enter("parse");
     let data = &data.trim().as_bytes();
     let columns = data
         .iter()
         .position(|tile| *tile == b'\n')
         .expect("invalid input");
     let rows = (data.len() + 1) / (columns + 1);
+    drop(parse);
+
+    let _span = spans::enter("solve");
 
     #[cfg(feature = "rayon")]
     let i = data.par_chunks(columns + 1);
@@ -59,6 +63,7 @@ pub fn part_1(data: &str) -> usize {
 #[cfg_attr(target_os = "none", inline(never))]
 #[must_use]
 pub fn part_2(data: &str) -> usize {
+    let parse = spans::enter("parse");
     let data = data.trim().as_bytes();
     let columns = data
         .iter()
@@ -70,6 +75,9 @@ pub fn part_2(data: &str) -> usize {
     let mut buffer = [[0u8; 141 * 141]; 2];
 
     buffer[0][0..len].copy_from_slice(data);
+    drop(parse);
+
+    let _span = spans::enter("solve");
 
     let mut result = 0;
     for i in core::iter::repeat(0..=1).flatten() {
@@ -132,6 +140,25 @@ pub fn part_2(data: &str) -> usize {
     unreachable!()
 }
 
+/// Day 4 of 2025.
+pub struct Day04;
+
+impl solution::Solution for Day04 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn part_1(input: &str) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(input: &str) -> Option<Self::Part2> {
+        Some(part_2(input))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,6 +6,7 @@
 pub fn part_1(data: &str) -> usize {
     data.split_once("\n\n")
         .map(|(ranges, ids)| {
+            let parse = spans::enter("parse");
             let mut id_ranges = [(0, 0); 200];
             let len = ranges
                 .lines()
@@ -18,7 +19,11 @@ pub fn part_1(data: &str) -> usize {
                     );
                 })
                 .count();
-            id_ranges[0..len].sort_unstable();
+            drop(parse);
+
+            spans::scope("sort", || id_ranges[0..len].sort_unstable());
+
+            let _span = spans::enter("solve");
 
             ids.lines()
                 .filter(|line| {
@@ -45,6 +50,7 @@ pub fn part_1(data: &str) -> usize {
 pub fn part_2(data: &str) -> u64 {
     data.split_once("\n\n")
         .map(|(ranges, _)| {
+            let parse = spans::enter("parse");
             let mut id_ranges = [(0, 0); 200];
             let len = ranges
                 .lines()
@@ -57,7 +63,11 @@ pub fn part_2(data: &str) -> u64 {
                     );
                 })
                 .count();
-            id_ranges[0..len].sort_unstable();
+            drop(parse);
+
+            spans::scope("sort", || id_ranges[0..len].sort_unstable());
+
+            let _span = spans::enter("solve");
 
             let (current, remainder) = id_ranges[0..len].split_first().expect("invalid input");
 
@@ -76,6 +86,25 @@ pub fn part_2(data: &str) -> u64 {
         .expect("invalid input")
 }
 
+/// Day 5 of 2025.
+pub struct Day05;
+
+impl solution::Solution for Day05 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn part_1(input: &str) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(input: &str) -> Option<Self::Part2> {
+        Some(part_2(input))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
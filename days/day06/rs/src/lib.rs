@@ -4,6 +4,7 @@
 #[cfg_attr(target_os = "none", inline(never))]
 #[must_use]
 pub fn part_1(data: &str) -> u64 {
+    let layout = spans::enter("layout");
     let table = data.as_bytes();
     let columns = table
         .iter()
@@ -20,6 +21,9 @@ pub fn part_1(data: &str) -> u64 {
     };
 
     let ops = &table[(rows - 1) * (columns + 1)..];
+    drop(layout);
+
+    let _span = spans::enter("solve");
 
     let mut total = 0;
     let mut column = 0;
@@ -56,6 +60,7 @@ pub fn part_1(data: &str) -> u64 {
 #[cfg_attr(target_os = "none", inline(never))]
 #[must_use]
 pub fn part_2(data: &str) -> u64 {
+    let layout = spans::enter("layout");
     let table = data.as_bytes();
     let columns = table
         .iter()
@@ -72,6 +77,9 @@ pub fn part_2(data: &str) -> u64 {
     };
 
     let ops = &table[(rows - 1) * (columns + 1)..];
+    drop(layout);
+
+    let _span = spans::enter("solve");
 
     let mut total = 0;
     let mut column = 0;
@@ -94,6 +102,25 @@ pub fn part_2(data: &str) -> u64 {
     total
 }
 
+/// Day 6 of 2025.
+pub struct Day06;
+
+impl solution::Solution for Day06 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 6;
+
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn part_1(input: &str) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(input: &str) -> Option<Self::Part2> {
+        Some(part_2(input))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,6 +4,7 @@
 #[cfg_attr(target_os = "none", inline(never))]
 #[must_use]
 pub fn part_1(data: &str) -> usize {
+    let layout = spans::enter("layout");
     let table = data.trim().as_bytes();
     let columns = table
         .iter()
@@ -22,6 +23,9 @@ pub fn part_1(data: &str) -> usize {
     } else {
         0u128
     };
+    drop(layout);
+
+    let _span = spans::enter("solve");
 
     let mut total = 0;
     for row in table.chunks(columns + 1).skip(1) {
@@ -56,11 +60,15 @@ pub fn part_1(data: &str) -> usize {
 #[cfg_attr(target_os = "none", inline(never))]
 #[must_use]
 pub fn part_2(data: &str) -> u64 {
+    let layout = spans::enter("layout");
     let table = data.trim().as_bytes();
     let columns = table
         .iter()
         .position(|&tile| tile == b'\n')
         .expect("Invalid input");
+    drop(layout);
+
+    let _span = spans::enter("solve");
 
     let mut state = [1u64; 200];
     for row in table.chunks(columns + 1).rev() {
@@ -84,6 +92,25 @@ pub fn part_2(data: &str) -> u64 {
     unreachable!()
 }
 
+/// Day 7 of 2025.
+pub struct Day07;
+
+impl solution::Solution for Day07 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 7;
+
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn part_1(input: &str) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(input: &str) -> Option<Self::Part2> {
+        Some(part_2(input))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,8 +131,17 @@ fn distance(p1: &Point, p2: &Point) -> Option<i32> {
 /// # Panics
 #[cfg_attr(target_os = "none", inline(never))]
 #[must_use]
-#[allow(clippy::cast_possible_truncation)]
 pub fn part_1<const SIZE: usize>(data: &str) -> u32 {
+    part_1_connecting(data, SIZE)
+}
+
+/// Part 1 connecting the `connections` closest pairs of junctions.
+///
+/// # Panics
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn part_1_connecting(data: &str, connections: usize) -> u32 {
+    let span = spans::enter("parse");
     let mut junctions = [(0, 0, 0); 1000];
     let junctions_len = data
         .lines()
@@ -141,7 +150,9 @@ pub fn part_1<const SIZE: usize>(data: &str) -> u32 {
             *element = parse(line);
         })
         .count();
+    drop(span);
 
+    let span = spans::enter("distances");
     let mut distances = [(0, (0, 0)); 6000];
     let len = junctions
         .iter()
@@ -162,10 +173,13 @@ pub fn part_1<const SIZE: usize>(data: &str) -> u32 {
         .count();
     assert!(len < 6000);
     distances[..len].sort_unstable();
+    drop(span);
+
+    let _span = spans::enter("merge");
 
     let mut sets = [[0u128; 8]; 1000];
     let mut sets = Merge::new(&mut sets, junctions_len);
-    for &(_, (i, j)) in distances.iter().take(SIZE) {
+    for &(_, (i, j)) in distances.iter().take(connections) {
         sets.merge(i as usize, j as usize);
     }
 
@@ -185,6 +199,7 @@ pub fn part_1<const SIZE: usize>(data: &str) -> u32 {
 #[must_use]
 #[allow(clippy::cast_possible_truncation)]
 pub fn part_2(data: &str) -> i64 {
+    let span = spans::enter("parse");
     let mut junctions = [(0, 0, 0); 1000];
     let junctions_len = data
         .lines()
@@ -193,7 +208,9 @@ pub fn part_2(data: &str) -> i64 {
             *element = parse(line);
         })
         .count();
+    drop(span);
 
+    let span = spans::enter("distances");
     let mut distances = [(0, (0, 0)); 6000];
     let len = junctions
         .iter()
@@ -214,6 +231,9 @@ pub fn part_2(data: &str) -> i64 {
         .count();
     assert!(len < 6000);
     distances[..len].sort_unstable();
+    drop(span);
+
+    let _span = spans::enter("merge");
 
     let mut sets = [[0u128; 8]; 1000];
     let mut sets = Merge::new(&mut sets, junctions_len);
@@ -227,6 +247,25 @@ pub fn part_2(data: &str) -> i64 {
     unreachable!()
 }
 
+/// Day 8 of 2025, connecting `SIZE` pairs in part 1.
+pub struct Day08<const SIZE: usize = 1000>;
+
+impl<const SIZE: usize> solution::Solution for Day08<SIZE> {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+
+    type Part1 = u32;
+    type Part2 = i64;
+
+    fn part_1(input: &str) -> Self::Part1 {
+        part_1::<SIZE>(input)
+    }
+
+    fn part_2(input: &str) -> Option<Self::Part2> {
+        Some(part_2(input))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -255,6 +294,7 @@ mod tests {
     #[test]
     fn test_part_1() {
         assert_eq!(part_1::<10>(INPUT), 40);
+        assert_eq!(part_1_connecting(INPUT, 10), 40);
     }
 
     #[test]
@@ -12,6 +12,7 @@ use rayon::prelude::*;
 #[cfg_attr(target_os = "none", inline(never))]
 #[must_use]
 pub fn part_1(data: &str) -> u64 {
+    let parse = spans::enter("parse");
     let mut tiles = [(0i64, 0i64); 500];
     let len = data
         .lines()
@@ -24,6 +25,9 @@ pub fn part_1(data: &str) -> u64 {
             };
         })
         .count();
+    drop(parse);
+
+    let _span = spans::enter("solve");
 
     #[cfg(feature = "rayon")]
     let i = tiles[..len - 1].into_par_iter();
@@ -133,6 +137,7 @@ const fn mask(min: isize, max: isize) -> [u128; 2] {
 #[cfg_attr(target_os = "none", inline(never))]
 #[must_use]
 pub fn part_2(data: &str) -> u64 {
+    let parse = spans::enter("parse");
     let mut tiles = [(0i64, 0i64); 500];
     let len = data
         .lines()
@@ -145,7 +150,9 @@ pub fn part_2(data: &str) -> u64 {
             };
         })
         .count();
+    drop(parse);
 
+    let span = spans::enter("compress");
     let mut xs = Vec::new();
     init_ss(&mut xs, &tiles[..len], |&(e, _)| e);
 
@@ -160,12 +167,17 @@ pub fn part_2(data: &str) -> u64 {
 
     let mut y2ys = HashMap::new();
     init_s2ss(&mut y2ys, &ys);
+    drop(span);
 
+    let span = spans::enter("map");
     let mut map = [[0u128; 2]; 256];
     init_map(&mut map, &tiles[..len], &x2xs, &y2ys);
 
     let mut lava = [[0u128; 2]; 256];
     init_lava(&mut lava, &map, columns, rows);
+    drop(span);
+
+    let _span = spans::enter("solve");
 
     #[cfg(feature = "rayon")]
     let i = tiles[..len - 1].into_par_iter();
@@ -201,6 +213,25 @@ pub fn part_2(data: &str) -> u64 {
         .expect("no solutions")
 }
 
+/// Day 9 of 2025.
+pub struct Day09;
+
+impl solution::Solution for Day09 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 9;
+
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn part_1(input: &str) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(input: &str) -> Option<Self::Part2> {
+        Some(part_2(input))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
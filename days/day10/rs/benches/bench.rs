@@ -4,9 +4,24 @@ use criterion::{Criterion, criterion_group, criterion_main};
 
 use day10 as day;
 
-const INPUT: &str = include_str!("../../input");
+const INPUT: &str = include_str!(env!("AOC_INPUT"));
+
+/// Check the answers of the input against the known ones, see `aoc::ANSWERS_FILE`.
+fn check_answers() {
+    if let Some(expected) = option_env!("AOC_ANSWER_1") {
+        assert_eq!(day::part_1(INPUT).to_string(), expected, "part 1 regressed");
+    }
+    if let Some(expected) = option_env!("AOC_ANSWER_2") {
+        let answer = day::part_2::<f32>(INPUT).to_string();
+        assert_eq!(answer, expected, "part 2 (f32) regressed");
+        let answer = day::part_2::<f64>(INPUT).to_string();
+        assert_eq!(answer, expected, "part 2 (f64) regressed");
+    }
+}
 
 pub fn criterion_benchmark(c: &mut Criterion) {
+    check_answers();
+
     let mut group = c.benchmark_group("day10");
     group.bench_function("part 1", |b| {
         b.iter(|| hint::black_box(day::part_1(hint::black_box(INPUT))));
@@ -3,6 +3,8 @@
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
 
+use core::marker::PhantomData;
+
 use numset::Set;
 use simplex::{self, Float, HeaplessVisitedStack, integer_simplex};
 
@@ -38,6 +40,7 @@ pub fn part_1(data: &str) -> u64 {
     let i = data.lines();
 
     i.map(|line| {
+        let parse = spans::enter("parse");
         let mut lights = 0u16;
 
         let mut buttons_len = 0;
@@ -60,7 +63,9 @@ pub fn part_1(data: &str) -> u64 {
             }
         }
 
-        bfs_lights(lights, &buttons[..buttons_len])
+        drop(parse);
+
+        spans::scope("bfs", || bfs_lights(lights, &buttons[..buttons_len]))
     })
     .sum()
 }
@@ -80,6 +85,7 @@ where
     let i = data.lines();
 
     i.map(|line| {
+        let parse = spans::enter("parse");
         let mut buttons_len = 0;
         let mut buttons = [0u16; 16];
 
@@ -139,6 +145,9 @@ where
                 *a = v;
             })
             .count();
+        drop(parse);
+
+        let _span = spans::enter("simplex");
 
         let mut stack = heapless::Vec::<_, 32>::new();
         let mut headers = heapless::Vec::<_, { 32 * 32 }>::new();
@@ -166,6 +175,37 @@ where
     .sum()
 }
 
+/// Float of the simplex selected by the features, f64 when both are enabled.
+#[cfg(feature = "f64")]
+pub type DefaultFloat = f64;
+
+/// Float of the simplex selected by the features, f64 when both are enabled.
+#[cfg(not(feature = "f64"))]
+pub type DefaultFloat = f32;
+
+/// Day 10 of 2025, solving the part 2 simplex with `F` floats.
+pub struct Day10<F = DefaultFloat>(PhantomData<F>);
+
+impl<F> solution::Solution for Day10<F>
+where
+    F: Float,
+    F: From<u8> + From<bool>,
+{
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 10;
+
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn part_1(input: &str) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(input: &str) -> Option<Self::Part2> {
+        Some(part_2::<F>(input))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -207,7 +247,7 @@ mod tests {
     #[cfg(feature = "input")]
     #[test]
     fn test_part_2_f32_vs_f64() {
-        let input = include_str!("../../input");
+        let input = include_str!(env!("AOC_INPUT"));
         assert_eq!(part_2::<f64>(input), part_2::<f32>(input),);
     }
 }
@@ -71,13 +71,15 @@ fn dfs_with<'a>(
 /// # Panics
 #[must_use]
 fn solve<'a>(data: &'a str, f: impl FnOnce(&Devices<'a>) -> u64) -> u64 {
+    let parse = spans::enter("parse");
     let mut devices = Devices::new();
     for line in data.lines() {
         let (from, tos) = line.split_once(": ").expect("Invalid line");
         devices.insert(from, tos).unwrap();
     }
+    drop(parse);
 
-    f(&devices)
+    spans::scope("dfs", || f(&devices))
 }
 
 /// # Panics
@@ -98,6 +100,25 @@ pub fn part_2(data: &str) -> u64 {
     })
 }
 
+/// Day 11 of 2025.
+pub struct Day11;
+
+impl solution::Solution for Day11 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 11;
+
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn part_1(input: &str) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(input: &str) -> Option<Self::Part2> {
+        Some(part_2(input))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
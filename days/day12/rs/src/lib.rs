@@ -5,6 +5,7 @@
 #[allow(clippy::cast_possible_truncation)]
 #[must_use]
 pub fn part_1(data: &str) -> usize {
+    let span = spans::enter("shapes");
     let mut shapes = [0; 6];
     for part in data.split("\n\n") {
         let mut lines = part.lines();
@@ -18,6 +19,9 @@ pub fn part_1(data: &str) -> usize {
                 .sum();
             shapes[id] = area;
         } else {
+            drop(span);
+            let _span = spans::enter("regions");
+
             return core::iter::once((left, right))
                 .chain(lines.map(|line| line.split_once(": ").expect("Invalid line")))
                 .filter(|(area, list)| {
@@ -42,6 +46,25 @@ pub fn part_1(data: &str) -> usize {
     unreachable!()
 }
 
+/// Day 12 of 2025, the last day of the event has no part 2.
+pub struct Day12;
+
+impl solution::Solution for Day12 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 12;
+
+    type Part1 = usize;
+    type Part2 = core::convert::Infallible;
+
+    fn part_1(input: &str) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part_2(_: &str) -> Option<Self::Part2> {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;